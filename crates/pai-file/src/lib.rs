@@ -1,20 +1,29 @@
-#![feature(ascii_char_variants)]
-#![feature(ascii_char)]
+use std::{fs, io, path::Path, sync::OnceLock};
 
-use std::{ascii, fs, io, path::Path};
+use crate::line_index::LineIndex;
+
+pub mod line_index;
+pub mod span;
 
 pub struct SourceFile {
     pub bytes: Vec<u8>,
+    line_index: OnceLock<LineIndex>,
 }
 
 impl SourceFile {
     pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, io::Error> {
         let bytes = fs::read(path)?;
 
-        // TODO: assert LF tail
-        assert!(bytes.ends_with(&[ascii::Char::LineFeed.to_u8()]));
+        Ok(Self {
+            bytes,
+            line_index: OnceLock::new(),
+        })
+    }
 
-        Ok(Self { bytes })
+    /// Built on the first call
+    pub fn line_index(&self) -> &LineIndex {
+        self.line_index
+            .get_or_init(|| LineIndex::new(self.source()))
     }
 
     /// # Safety
@@ -0,0 +1,179 @@
+use crate::span::Pos;
+
+/// Zero-based line and column
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LineCol {
+    pub line: u32,
+    pub col: u32,
+}
+
+impl LineCol {
+    pub fn new(line: u32, col: u32) -> Self {
+        Self { line, col }
+    }
+}
+
+/// Unit of [LineCol::col]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnUnit {
+    /// UTF-8 bytes
+    Byte,
+    /// Unicode scalar values
+    Char,
+    /// UTF-16 code units, e.g. LSP and JavaScript string index
+    Utf16,
+}
+
+/// Line start positions of the source, lookup in O(log n)
+///
+/// [Line terminators](https://tc39.es/ecma262/#sec-line-terminators) are LF, CR, CRLF, LS (U+2028) and PS (U+2029)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineIndex {
+    /// Start position of each line, the first is 0
+    starts: Vec<Pos>,
+    /// End position of each line, excluding the line terminator
+    ends: Vec<Pos>,
+    /// Non-ASCII chars, ends with a sentinel at the source end
+    wide: Vec<WideChar>,
+}
+
+/// Non-ASCII char, with the prefix sums of the column deltas of the chars before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WideChar {
+    pos: Pos,
+    /// Sum of `bytes - 1`
+    char_delta: u32,
+    /// Sum of `bytes - utf16 units`
+    utf16_delta: u32,
+}
+
+impl WideChar {
+    fn delta(&self, unit: ColumnUnit) -> u32 {
+        match unit {
+            ColumnUnit::Byte => 0,
+            ColumnUnit::Char => self.char_delta,
+            ColumnUnit::Utf16 => self.utf16_delta,
+        }
+    }
+}
+
+impl LineIndex {
+    pub fn new(src: &str) -> Self {
+        let bytes = src.as_bytes();
+
+        let mut starts = vec![0];
+        let mut ends = Vec::new();
+        let mut wide = Vec::new();
+
+        let mut char_delta = 0;
+        let mut utf16_delta = 0;
+        let mut pos = 0;
+
+        while pos < bytes.len() {
+            let byte = bytes[pos];
+
+            let terminator = match byte {
+                b'\n' => 1,
+                b'\r' if bytes.get(pos + 1) == Some(&b'\n') => 2,
+                b'\r' => 1,
+                // U+2028 U+2029 : [0xE2, 0x80, 0xA8 | 0xA9]
+                0xE2 if bytes[pos + 1] == 0x80 && matches!(bytes[pos + 2], 0xA8 | 0xA9) => 3,
+                _ => 0,
+            };
+
+            if terminator > 0 {
+                ends.push(pos as Pos);
+                starts.push((pos + terminator) as Pos);
+            }
+
+            if byte < 0x80 {
+                pos += terminator.max(1);
+                continue
+            }
+
+            let ch = src[pos..].chars().next().unwrap_or_default();
+            let len = ch.len_utf8();
+
+            wide.push(WideChar {
+                pos: pos as Pos,
+                char_delta,
+                utf16_delta,
+            });
+
+            char_delta += len as u32 - 1;
+            utf16_delta += (len - ch.len_utf16()) as u32;
+            pos += len;
+        }
+
+        ends.push(bytes.len() as Pos);
+        wide.push(WideChar {
+            pos: bytes.len() as Pos,
+            char_delta,
+            utf16_delta,
+        });
+
+        Self { starts, ends, wide }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// Byte range of the line, excluding the line terminator
+    pub fn line_range(&self, line: u32) -> Option<(Pos, Pos)> {
+        let line = line as usize;
+
+        Some((*self.starts.get(line)?, *self.ends.get(line)?))
+    }
+
+    /// Line of the position, None if out of the source
+    pub fn line(&self, pos: Pos) -> Option<u32> {
+        if pos > *self.ends.last()? {
+            return None
+        }
+
+        Some((self.starts.partition_point(|&start| start <= pos) - 1) as u32)
+    }
+
+    /// Position should be a char boundary
+    pub fn line_col(&self, pos: Pos, unit: ColumnUnit) -> Option<LineCol> {
+        let line = self.line(pos)?;
+        let start = self.starts[line as usize];
+
+        let col = (pos - start) - (self.delta(pos, unit) - self.delta(start, unit));
+
+        Some(LineCol::new(line, col))
+    }
+
+    /// Inverse of [LineIndex::line_col], None if the column is out of the line or inside a char,
+    /// e.g. between the UTF-16 surrogate pair of `😀`
+    pub fn offset(&self, line_col: LineCol, unit: ColumnUnit) -> Option<Pos> {
+        let (start, end) = self.line_range(line_col.line)?;
+
+        let lo = self.wide.partition_point(|wide| wide.pos < start);
+        let hi = self.wide.partition_point(|wide| wide.pos < end);
+        let base = self.wide[lo].delta(unit);
+
+        // non-ASCII chars of the line before the column
+        let count = self.wide[lo..hi]
+            .partition_point(|wide| (wide.pos - start) - (wide.delta(unit) - base) < line_col.col);
+
+        let pos = start + line_col.col + (self.wide[lo + count].delta(unit) - base);
+
+        // the last non-ASCII char before the column, bytes from its char delta
+        if count > 0 {
+            let (prev, next) = (self.wide[lo + count - 1], self.wide[lo + count]);
+
+            if pos < prev.pos + 1 + (next.char_delta - prev.char_delta) {
+                return None
+            }
+        }
+
+        (pos <= end).then_some(pos)
+    }
+
+    /// Column delta of the non-ASCII chars before the position
+    fn delta(&self, pos: Pos, unit: ColumnUnit) -> u32 {
+        self.wide[self.wide.partition_point(|wide| wide.pos < pos)].delta(unit)
+    }
+}
@@ -0,0 +1,35 @@
+/// Source code byte position
+pub type Pos = u32;
+
+/// Syntax context tier
+pub type Tier = u32;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    // start byte pos
+    pub lo: Pos,
+    // end byte pos
+    pub hi: Pos,
+    // syntax context tier
+    pub tier: Tier,
+}
+
+impl Span {
+    pub const DUMMY: Self = Self {
+        lo: 0,
+        hi: 0,
+        tier: 0,
+    };
+
+    pub fn new(lo: Pos, hi: Pos) -> Self {
+        Self { lo, hi, tier: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        (self.hi - self.lo) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lo == self.hi
+    }
+}
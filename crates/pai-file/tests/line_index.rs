@@ -0,0 +1,82 @@
+use pai_file::line_index::{ColumnUnit, LineCol, LineIndex};
+
+#[test]
+fn line_terminators() {
+    let src = "a\nb\r\nc\rd\u{2028}e\u{2029}f";
+    let index = LineIndex::new(src);
+
+    assert_eq!(index.line_count(), 6);
+
+    let lines: Vec<_> = (0..6)
+        .map(|line| {
+            let (lo, hi) = index.line_range(line).unwrap();
+            &src[lo as usize..hi as usize]
+        })
+        .collect();
+    assert_eq!(lines, ["a", "b", "c", "d", "e", "f"]);
+
+    // CR and LF of CRLF are in the same line
+    assert_eq!(index.line(3), Some(1));
+    assert_eq!(index.line(4), Some(1));
+    assert_eq!(index.line(5), Some(2));
+    assert_eq!(index.line(src.len() as u32), Some(5));
+    assert_eq!(index.line(src.len() as u32 + 1), None);
+
+    // trailing line terminator starts an empty line
+    let index = LineIndex::new("a\n");
+    assert_eq!(index.line_count(), 2);
+    assert_eq!(index.line_range(1), Some((2, 2)));
+}
+
+#[test]
+fn line_col() {
+    // `é` 2 bytes 1 UTF-16 unit, `😀` 4 bytes 2 UTF-16 units
+    let src = "let a\n  é = '😀x';\n";
+    let index = LineIndex::new(src);
+
+    let x = src.find('x').unwrap() as u32;
+
+    for (unit, col) in [
+        (ColumnUnit::Byte, 12),
+        (ColumnUnit::Char, 8),
+        (ColumnUnit::Utf16, 9),
+    ] {
+        let line_col = index.line_col(x, unit).unwrap();
+        assert_eq!(line_col, LineCol::new(1, col));
+        assert_eq!(index.offset(line_col, unit), Some(x));
+    }
+
+    // every char boundary round trips
+    for (pos, _) in src.char_indices() {
+        for unit in [ColumnUnit::Byte, ColumnUnit::Char, ColumnUnit::Utf16] {
+            let line_col = index.line_col(pos as u32, unit).unwrap();
+            assert_eq!(index.offset(line_col, unit), Some(pos as u32));
+        }
+    }
+
+    // column out of the line
+    assert_eq!(index.offset(LineCol::new(0, 6), ColumnUnit::Char), None);
+    assert_eq!(index.offset(LineCol::new(3, 0), ColumnUnit::Char), None);
+
+    // column inside a char
+    let index = LineIndex::new("😀x\né");
+    assert_eq!(index.offset(LineCol::new(0, 1), ColumnUnit::Utf16), None);
+    assert_eq!(index.offset(LineCol::new(0, 2), ColumnUnit::Utf16), Some(4));
+    assert_eq!(index.offset(LineCol::new(0, 2), ColumnUnit::Byte), None);
+    assert_eq!(index.offset(LineCol::new(1, 1), ColumnUnit::Byte), None);
+    assert_eq!(index.offset(LineCol::new(1, 1), ColumnUnit::Char), Some(8));
+
+    // any offset is a char boundary
+    let src = "a😀é\u{2028}😀😀x\ré";
+    let index = LineIndex::new(src);
+
+    for line in 0..3 {
+        for col in 0..16 {
+            for unit in [ColumnUnit::Byte, ColumnUnit::Char, ColumnUnit::Utf16] {
+                if let Some(pos) = index.offset(LineCol::new(line, col), unit) {
+                    assert!(src.is_char_boundary(pos as usize), "{line}:{col} {unit:?}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasherDefault, Hash, Hasher},
+    sync::{OnceLock, RwLock},
+};
+
+use crate::scanner::{contextual::Contextual, ident::Ident, keyword, keyword::Keyword, lit::Str};
+
+/// Interned identifier or string literal text, compare and hash by id
+///
+/// Keywords and contextual keywords have fixed ids, e.g. `Atom::from(Keyword::Let)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Atom(u32);
+
+impl Atom {
+    pub fn id(self) -> u32 {
+        self.0
+    }
+
+    /// Seeded by every [Interner]
+    pub fn is_static(self) -> bool {
+        (self.0 as usize) < static_names().len()
+    }
+}
+
+impl From<Keyword> for Atom {
+    fn from(keyword: Keyword) -> Self {
+        Self(keyword as u32)
+    }
+}
+
+impl From<Contextual> for Atom {
+    fn from(contextual: Contextual) -> Self {
+        Self((Keyword::ALL.len() + contextual as usize) as u32)
+    }
+}
+
+/// Names other than keywords and contextual keywords, seeded after them
+const COMMON: &[&str] = &[
+    keyword::ARGUMENTS,
+    keyword::EVAL,
+    "prototype",
+    "__proto__",
+    "length",
+    "exports",
+];
+
+/// Text of the static atoms, in the order of their ids
+fn static_names() -> &'static [&'static str] {
+    static NAMES: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+    NAMES.get_or_init(|| {
+        let keywords = Keyword::ALL.iter().map(Keyword::raw);
+        let contextual = Contextual::ALL.iter().map(Contextual::raw);
+
+        keywords
+            .chain(contextual)
+            .chain(COMMON.iter().copied())
+            .collect()
+    })
+}
+
+/// Shard count, power of 2, lower bits of the dynamic atom id
+const SHARDS: usize = 16;
+
+const SHARD_BITS: u32 = SHARDS.trailing_zeros();
+
+/// Atom table shareable across files and threads, e.g. in an [Arc](std::sync::Arc)
+///
+/// Text hash is computed once per lookup and stored with the text, never rehashed.
+/// The table is sharded by the hash to reduce lock contention
+#[derive(Debug)]
+pub struct Interner {
+    shards: [RwLock<Shard>; SHARDS],
+}
+
+#[derive(Debug, Default)]
+struct Shard {
+    atoms: HashMap<Key, Atom, BuildHasherDefault<KeyHasher>>,
+    /// Text of the dynamic atoms of the shard, boxed so that the keys never dangle
+    names: Vec<Box<str>>,
+}
+
+/// Text with its precomputed hash
+#[derive(Debug, Clone, Copy)]
+struct Key {
+    hash: u64,
+    name: *const str,
+}
+
+// SAFETY: name points to a static text or a boxed text of the shard, which is never dropped before the shard
+unsafe impl Send for Key {}
+unsafe impl Sync for Key {}
+
+impl Key {
+    fn new(name: &str) -> Self {
+        Self::new_with_hash(hash(name), name)
+    }
+
+    fn new_with_hash(hash: u64, name: &str) -> Self {
+        Self { hash, name }
+    }
+
+    fn name(&self) -> &str {
+        unsafe { &*self.name }
+    }
+}
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.name() == other.name()
+    }
+}
+
+impl Eq for Key {}
+
+impl Hash for Key {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash)
+    }
+}
+
+/// Pass the precomputed hash through
+#[derive(Default)]
+struct KeyHasher(u64);
+
+impl Hasher for KeyHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, _: &[u8]) {
+        unreachable!("Key writes the precomputed hash only")
+    }
+
+    fn write_u64(&mut self, hash: u64) {
+        self.0 = hash
+    }
+}
+
+/// [FxHash](https://github.com/rust-lang/rustc-hash), fast for the short names
+fn hash(name: &str) -> u64 {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+    let add = |hash: u64, word: u64| (hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+
+    let (chunks, remainder) = name.as_bytes().as_chunks::<8>();
+    let mut hash = chunks
+        .iter()
+        .fold(0, |hash, &chunk| add(hash, u64::from_le_bytes(chunk)));
+
+    for &byte in remainder {
+        hash = add(hash, byte as u64)
+    }
+
+    add(hash, name.len() as u64)
+}
+
+impl Default for Interner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Interner {
+    /// Seeded with the keywords, contextual keywords and common names
+    pub fn new() -> Self {
+        let interner = Self {
+            shards: Default::default(),
+        };
+
+        for (id, name) in static_names().iter().enumerate() {
+            let key = Key::new(name);
+
+            interner
+                .shard(key.hash)
+                .write()
+                .unwrap()
+                .atoms
+                .insert(key, Atom(id as u32));
+        }
+
+        interner
+    }
+
+    /// Atom of the text, insert if absent
+    pub fn intern(&self, name: &str) -> Atom {
+        let key = Key::new(name);
+        let shard = self.shard(key.hash);
+
+        if let Some(&atom) = shard.read().unwrap().atoms.get(&key) {
+            return atom
+        }
+
+        let mut shard = shard.write().unwrap();
+
+        // inserted by another thread between the locks
+        if let Some(&atom) = shard.atoms.get(&key) {
+            return atom
+        }
+
+        let index = shard.names.len();
+        let offset = (index << SHARD_BITS | (key.hash as usize & (SHARDS - 1))) as u32;
+        let atom = Atom(static_names().len() as u32 + offset);
+
+        let name: Box<str> = name.into();
+        let key = Key::new_with_hash(key.hash, &name);
+
+        shard.names.push(name);
+        shard.atoms.insert(key, atom);
+
+        atom
+    }
+
+    /// Atom of the text if interned
+    pub fn get(&self, name: &str) -> Option<Atom> {
+        let key = Key::new(name);
+
+        self.shard(key.hash)
+            .read()
+            .unwrap()
+            .atoms
+            .get(&key)
+            .copied()
+    }
+
+    /// Text of the atom, which must be interned by this interner
+    pub fn resolve(&self, atom: Atom) -> &str {
+        if let Some(name) = static_names().get(atom.0 as usize) {
+            return name
+        }
+
+        let offset = atom.0 as usize - static_names().len();
+        let shard = self.shards[offset & (SHARDS - 1)].read().unwrap();
+        let name: *const str = &*shard.names[offset >> SHARD_BITS];
+
+        // SAFETY: boxed text is never moved nor dropped while the interner lives
+        unsafe { &*name }
+    }
+
+    /// Count of the atoms, including the static ones
+    pub fn len(&self) -> usize {
+        let dynamic: usize = self
+            .shards
+            .iter()
+            .map(|shard| shard.read().unwrap().names.len())
+            .sum();
+
+        static_names().len() + dynamic
+    }
+
+    /// Never, seeded with the static atoms
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    fn shard(&self, hash: u64) -> &RwLock<Shard> {
+        &self.shards[hash as usize & (SHARDS - 1)]
+    }
+}
+
+impl Ident<'_> {
+    /// Atom of the [StringValue](Ident::name)
+    pub fn atom(&self, interner: &Interner) -> Atom {
+        match self.contextual {
+            Some(contextual) => Atom::from(contextual),
+            None => interner.intern(&self.name),
+        }
+    }
+}
+
+impl Str<'_> {
+    /// Atom of the [SV](Str::cooked)
+    pub fn atom(&self, interner: &Interner) -> Atom {
+        interner.intern(&self.cooked)
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use pai_file::SourceFile;
+
+use crate::{
+    scanner::{diagnostic::Diagnostic, token::Token},
+    Lexer,
+};
+
+/// Tokens and lexical errors of a source
+#[derive(Debug)]
+pub struct Lexed<'s> {
+    pub tokens: Vec<Token<'s>>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl<'s> Lexed<'s> {
+    pub fn new(src: &'s str) -> Self {
+        let mut lexer = Lexer::new(src);
+        let tokens = lexer.by_ref().collect();
+
+        Self {
+            tokens,
+            diagnostics: lexer.into_diagnostics(),
+        }
+    }
+}
+
+/// Lex the files with [available parallelism][thread::available_parallelism], see [lex_sources]
+pub fn lex_files(files: &[SourceFile]) -> Vec<Lexed<'_>> {
+    let sources: Vec<_> = files.iter().map(SourceFile::source).collect();
+    let threads = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+
+    lex_sources(&sources, threads)
+}
+
+/// Lex the sources across `threads` scoped threads, the result is in the order of the sources
+///
+/// Each thread takes the next unlexed source, so that large files do not hold up the others
+pub fn lex_sources<'s>(sources: &[&'s str], threads: NonZeroUsize) -> Vec<Lexed<'s>> {
+    let threads = threads.get().min(sources.len());
+
+    if threads <= 1 {
+        return sources.iter().map(|src| Lexed::new(src)).collect()
+    }
+
+    let next = AtomicUsize::new(0);
+
+    let lexed: Vec<Vec<(usize, Lexed<'s>)>> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut lexed = Vec::new();
+
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+
+                        let Some(src) = sources.get(index) else {
+                            return lexed
+                        };
+
+                        lexed.push((index, Lexed::new(src)));
+                    }
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|err| std::panic::resume_unwind(err))
+            })
+            .collect()
+    });
+
+    let mut ordered: Vec<Option<Lexed<'s>>> = sources.iter().map(|_| None).collect();
+
+    for (index, item) in lexed.into_iter().flatten() {
+        ordered[index] = Some(item);
+    }
+
+    ordered.into_iter().flatten().collect()
+}
@@ -0,0 +1,251 @@
+use std::ops::Range;
+
+use pai_file::span::{Pos, Span};
+
+use crate::{
+    scanner::{
+        goal::Goal,
+        lit::Lit,
+        punctuator::Punctuator,
+        token::Token,
+        unit::{Kind, Unit},
+    },
+    Lexer,
+};
+
+/// Replace `span` of the old source with `text`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextEdit<'a> {
+    pub span: Span,
+    pub text: &'a str,
+}
+
+impl<'a> TextEdit<'a> {
+    pub fn new(span: Span, text: &'a str) -> Self {
+        Self { span, text }
+    }
+
+    /// Shift of the positions after the edit
+    pub fn delta(&self) -> i64 {
+        self.text.len() as i64 - self.span.len() as i64
+    }
+}
+
+/// Tokens changed by a [TextEdit]
+#[derive(Debug)]
+pub struct Relexed<'s> {
+    /// Index range of the replaced old tokens
+    pub old: Range<usize>,
+    /// New tokens in place of the old ones
+    pub tokens: Vec<Token<'s>>,
+    /// Shift of the spans of the old tokens after the range, see [TextEdit::delta]
+    pub delta: i64,
+}
+
+/// Tokens between the [Restarts] points, bounds the tokens replayed by [relex]
+pub const RESTART_INTERVAL: usize = 64;
+
+/// Scanner state before every [RESTART_INTERVAL] tokens or so, kept along with the tokens
+#[derive(Debug, Clone)]
+pub struct Restarts {
+    /// Token index and the state before it, sorted by index, the first is before token 0
+    points: Vec<(usize, State)>,
+}
+
+impl Restarts {
+    pub fn new(tokens: &[Token]) -> Self {
+        let mut restarts = Self {
+            points: vec![(0, State::default())],
+        };
+
+        restarts.replay(tokens, tokens.len(), tokens.len());
+        restarts
+    }
+
+    /// Update after the old tokens are spliced with the [Relexed] ones into `tokens`
+    ///
+    /// Points after the replaced range are shifted, the scanner state is the same after resync
+    pub fn splice(&mut self, tokens: &[Token], relexed: &Relexed) {
+        let Range { start, end } = relexed.old.clone();
+        let new_end = start + relexed.tokens.len();
+
+        let after = self.points.partition_point(|&(index, _)| index <= end);
+        let mut tail = self.points.split_off(after);
+
+        for (index, _) in &mut tail {
+            *index = *index - end + new_end
+        }
+
+        let before = self.points.partition_point(|&(index, _)| index <= start);
+        self.points.truncate(before);
+
+        let next = tail.first().map_or(tokens.len(), |&(index, _)| index);
+
+        self.replay(tokens, new_end, next);
+        self.points.extend(tail);
+    }
+
+    /// Last point at or before the token
+    fn before(&self, index: usize) -> &(usize, State) {
+        let after = self.points.partition_point(|&(point, _)| point <= index);
+
+        &self.points[after - 1]
+    }
+
+    /// Add points from the last one through `new_end`, then until `next` is in reach
+    fn replay(&mut self, tokens: &[Token], new_end: usize, next: usize) {
+        let Some((mut last, state)) = self.points.last() else {
+            return
+        };
+
+        let mut state = state.clone();
+        let mut index = last;
+
+        while index < next && (index < new_end || next - last > RESTART_INTERVAL) {
+            if index - last == RESTART_INTERVAL {
+                self.points.push((index, state.clone()));
+                last = index;
+            }
+
+            state.next(&tokens[index]);
+            index += 1;
+        }
+    }
+}
+
+/// Scanner state inferred from the tokens before
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct State {
+    braces: Vec<u32>,
+    goal: Goal,
+}
+
+impl State {
+    /// Same as the scanner after `token`
+    fn next(&mut self, token: &Token) {
+        match &token.unit {
+            Unit::Lit(Lit::TemplateHead(_)) => self.braces.push(0),
+            Unit::Lit(Lit::TemplateTail(_)) => {
+                self.braces.pop();
+            },
+            Unit::Punctuator(Punctuator::LBrace) => {
+                if let Some(depth) = self.braces.last_mut() {
+                    *depth += 1
+                }
+            },
+            Unit::Punctuator(Punctuator::RBrace) => {
+                if let Some(depth) = self.braces.last_mut() {
+                    *depth -= 1
+                }
+            },
+            _ => {},
+        }
+
+        if let Some(goal) = Goal::next(&token.unit) {
+            self.goal = goal
+        }
+    }
+}
+
+/// Re-lex the edited source from the last safe restart point before the edit, until the tokens
+/// resynchronize with the old ones after the edit
+///
+/// `lexer` is a new lexer of the edited source, `old` are all the tokens of the old source with
+/// their `restarts`, at most [RESTART_INTERVAL] tokens before the edit are replayed.
+/// Block comments, strings and templates opened or closed by the edit are re-lexed to the end
+/// of their effect. JSX mode is not tracked
+pub fn relex<'s>(
+    lexer: &mut Lexer<'s>,
+    old: &[Token],
+    restarts: &Restarts,
+    edit: &TextEdit,
+) -> Relexed<'s> {
+    let delta = edit.delta();
+
+    // the token right before the edit may be extended, e.g. `ab|` to `abc`
+    let first = old
+        .partition_point(|token| token.span.hi < edit.span.lo)
+        .saturating_sub(1);
+
+    let (point, state) = restarts.before(first);
+    let mut state = state.clone();
+
+    if first > 0 {
+        old[*point..first]
+            .iter()
+            .for_each(|token| state.next(token));
+
+        let restart = &old[first];
+
+        lexer.scanner.resume(
+            restart.span.lo,
+            state.braces.clone(),
+            state.goal,
+            restart.new_line,
+        );
+    }
+
+    let shift = |pos: Pos| (pos as i64 + delta) as Pos;
+
+    let mut tokens = Vec::new();
+    let mut index = first;
+
+    while let Some(token) = lexer.next() {
+        // old tokens before the new token are replaced
+        while let Some(prev) = old.get(index) {
+            if prev.span.lo >= edit.span.hi && shift(prev.span.lo) >= token.span.lo {
+                break
+            }
+
+            state.next(prev);
+            index += 1;
+        }
+
+        if let Some(prev) = old.get(index) {
+            if is_synced(lexer, &token, prev, &state, shift) {
+                return Relexed {
+                    old: first..index,
+                    tokens,
+                    delta,
+                }
+            }
+        }
+
+        tokens.push(token);
+    }
+
+    Relexed {
+        old: first..old.len(),
+        tokens,
+        delta,
+    }
+}
+
+/// New token is the same as the old one after the edit, and so are the scanner states after them
+fn is_synced(
+    lexer: &Lexer,
+    token: &Token,
+    prev: &Token,
+    state: &State,
+    shift: impl Fn(Pos) -> Pos,
+) -> bool {
+    let kind = token.unit.kind();
+
+    // line terminators in comment and trivia are carried to the next token
+    if matches!(kind, Kind::Comment | Kind::Trivia) {
+        return false
+    }
+
+    if token.span.lo != shift(prev.span.lo)
+        || token.span.hi != shift(prev.span.hi)
+        || token.new_line != prev.new_line
+        || kind != prev.unit.kind()
+    {
+        return false
+    }
+
+    let mut state = state.clone();
+    state.next(prev);
+
+    state.braces == lexer.scanner.braces() && state.goal == lexer.goal()
+}
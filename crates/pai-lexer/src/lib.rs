@@ -1,40 +1,181 @@
 #![feature(str_internals)]
-#![feature(ptr_sub_ptr)]
 #![feature(slice_from_ptr_range)]
+#![feature(portable_simd)]
 
 extern crate core;
 
-use pai_error::PResult;
 use scanner::Scanner;
 
-use crate::scanner::unit::Unit;
+use crate::{
+    lookahead::{Lookahead, LOOKAHEAD},
+    scanner::{
+        checkpoint::Checkpoint,
+        diagnostic::Diagnostic,
+        goal::{Goal, SourceType},
+        jsx::JsxMode,
+        token::Token,
+        unit::Kind,
+    },
+};
 
 #[macro_use]
 pub mod macros;
 
+pub mod atom;
+pub mod batch;
+pub mod incremental;
+pub mod lookahead;
 pub mod scanner;
 
 #[derive(Debug)]
 pub struct Lexer<'s> {
     pub src: &'s str,
     pub scanner: Scanner<'s>,
+    lookahead: Lookahead<'s>,
 }
 
 impl<'s> Lexer<'s> {
-    /// # Safety
-    /// src must end with new line. e.g. LF(U+000A)
+    /// Any source text, no trailing line terminator is required
     pub fn new(src: &'s str) -> Self {
         Self {
             src,
             scanner: Scanner::new(src),
+            lookahead: Lookahead::default(),
         }
     }
+
+    pub fn goal(&self) -> Goal {
+        self.scanner.goal()
+    }
+
+    /// Override the goal of the next unit, see [Scanner::set_goal]
+    pub fn set_goal(&mut self, goal: Goal) {
+        self.unpeek();
+        self.scanner.set_goal(goal)
+    }
+
+    pub fn source_type(&self) -> SourceType {
+        self.scanner.source_type()
+    }
+
+    /// HTML-like comments are only recognized in [SourceType::Script]
+    pub fn set_source_type(&mut self, source_type: SourceType) {
+        self.unpeek();
+        self.scanner.set_source_type(source_type)
+    }
+
+    pub fn jsx_mode(&self) -> Option<JsxMode> {
+        self.scanner.jsx_mode()
+    }
+
+    /// Switch JSX context, see [Scanner::set_jsx_mode]
+    pub fn set_jsx_mode(&mut self, jsx_mode: Option<JsxMode>) {
+        self.unpeek();
+        self.scanner.set_jsx_mode(jsx_mode)
+    }
+
+    /// Lossless mode, see [Scanner::set_trivia]
+    pub fn set_trivia(&mut self, trivia: bool) {
+        self.unpeek();
+        self.scanner.set_trivia(trivia)
+    }
+
+    /// Lexical errors of the units scanned so far
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        self.scanner.diagnostics()
+    }
+
+    pub fn into_diagnostics(self) -> Vec<Diagnostic> {
+        self.scanner.into_diagnostics()
+    }
+}
+
+/// Lookahead without re-lexing, see [Lookahead]
+///
+/// Changing the goal, source type, JSX mode or trivia mode rewinds the peeked tokens,
+/// they are scanned again in the new context
+impl<'s> Lexer<'s> {
+    /// Next token without consuming it
+    pub fn peek(&mut self) -> Option<&Token<'s>> {
+        self.peek_nth(0)
+    }
+
+    /// Nth token ahead without consuming, [None] at the end of input
+    /// or when `n` is not less than [LOOKAHEAD]
+    pub fn peek_nth(&mut self, n: usize) -> Option<&Token<'s>> {
+        if n >= LOOKAHEAD {
+            return None
+        }
+
+        while self.lookahead.len() <= n {
+            let checkpoint = self.scanner.checkpoint();
+            let token = self.scanner.next_token()?;
+
+            self.lookahead.push_back(checkpoint, token);
+        }
+
+        self.lookahead.get(n)
+    }
+
+    /// [Kind] of the nth token ahead, see [Lexer::peek_nth]
+    pub fn peek_kind(&mut self, n: usize) -> Option<Kind> {
+        self.peek_nth(n).map(|token| token.unit.kind())
+    }
+
+    /// Rewind the scanner before the peeked tokens
+    fn unpeek(&mut self) {
+        if let Some(checkpoint) = self.lookahead.clear() {
+            self.scanner.restore(checkpoint)
+        }
+    }
+}
+
+/// Backtrack for speculative parsing, see [Checkpoint]
+impl<'s> Lexer<'s> {
+    /// State before the peeked tokens if any
+    pub fn checkpoint(&self) -> Checkpoint {
+        match self.lookahead.front() {
+            Some(checkpoint) => checkpoint.clone(),
+            None => self.scanner.checkpoint(),
+        }
+    }
+
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.lookahead.clear();
+        self.scanner.restore(checkpoint)
+    }
+}
+
+/// Rescan the last token, see [scanner::rescan]
+impl<'s> Lexer<'s> {
+    pub fn rescan_gt(&mut self, token: &Token<'s>) -> Token<'s> {
+        self.unpeek();
+        self.scanner.rescan_gt(token)
+    }
+
+    pub fn rescan_lt(&mut self, token: &Token<'s>) -> Token<'s> {
+        self.unpeek();
+        self.scanner.rescan_lt(token)
+    }
+
+    pub fn rescan_slash(&mut self, token: &Token<'s>) -> Token<'s> {
+        self.unpeek();
+        self.scanner.rescan_slash(token)
+    }
+
+    pub fn rescan_template_tail(&mut self, token: &Token<'s>) -> Token<'s> {
+        self.unpeek();
+        self.scanner.rescan_template_tail(token)
+    }
 }
 
 impl<'s> Iterator for Lexer<'s> {
-    type Item = PResult<Unit<'s>>;
+    type Item = Token<'s>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.scanner.next_unit()
+        match self.lookahead.pop_front() {
+            Some(token) => Some(token),
+            None => self.scanner.next_token(),
+        }
     }
 }
@@ -0,0 +1,90 @@
+use crate::scanner::{checkpoint::Checkpoint, token::Token};
+
+/// Max count of the tokens peeked ahead, e.g. `async function`, `let [`, `type X =`
+pub const LOOKAHEAD: usize = 4;
+
+/// Fixed size ring buffer of the peeked tokens, never allocate
+///
+/// Each token is saved with the scanner state before it, so that the [Lexer](crate::Lexer)
+/// can rewind the peeked tokens when the context changes
+#[derive(Debug)]
+pub struct Lookahead<'s> {
+    slots: [Option<(Checkpoint, Token<'s>)>; LOOKAHEAD],
+    head: usize,
+    len: usize,
+}
+
+impl Default for Lookahead<'_> {
+    fn default() -> Self {
+        Self {
+            slots: [const { None }; LOOKAHEAD],
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<'s> Lookahead<'s> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == LOOKAHEAD
+    }
+
+    /// Nth peeked token
+    pub fn get(&self, n: usize) -> Option<&Token<'s>> {
+        if n >= self.len {
+            return None
+        }
+
+        self.slots[(self.head + n) % LOOKAHEAD]
+            .as_ref()
+            .map(|(_, token)| token)
+    }
+
+    /// Scanner state before the first peeked token
+    pub fn front(&self) -> Option<&Checkpoint> {
+        self.slots[self.head]
+            .as_ref()
+            .map(|(checkpoint, _)| checkpoint)
+    }
+
+    pub fn push_back(&mut self, checkpoint: Checkpoint, token: Token<'s>) {
+        assert!(!self.is_full(), "Lookahead is full");
+
+        self.slots[(self.head + self.len) % LOOKAHEAD] = Some((checkpoint, token));
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<Token<'s>> {
+        if self.is_empty() {
+            return None
+        }
+
+        let (_, token) = self.slots[self.head].take()?;
+
+        self.head = (self.head + 1) % LOOKAHEAD;
+        self.len -= 1;
+
+        Some(token)
+    }
+
+    /// Drop the peeked tokens, return the scanner state before them
+    pub fn clear(&mut self) -> Option<Checkpoint> {
+        let front = self.slots[self.head]
+            .take()
+            .map(|(checkpoint, _)| checkpoint);
+
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+        self.head = 0;
+        self.len = 0;
+
+        front
+    }
+}
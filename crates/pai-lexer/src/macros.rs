@@ -1,6 +1,6 @@
 macro_rules! declare_enum {
     (NAME: $name:ident, $($item:ident=$lit:literal),* $(,)?) => {
-        #[derive(Debug, Clone, Copy)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         #[repr(u8)]
         pub enum $name {
             $($item,)*
@@ -9,6 +9,9 @@ macro_rules! declare_enum {
         const LOOKUP_TABLE: &[&str] = &[$($lit,)*];
 
         impl $name {
+            /// All variants in declaration order
+            pub const ALL: &'static [Self] = &[$(Self::$item,)*];
+
             pub fn raw(&self) -> &str {
                 LOOKUP_TABLE[*self as usize]
             }
@@ -22,10 +25,14 @@ macro_rules! char {
     };
 }
 
-// TODO: use ParserError
+/// Lexical error with [Code][crate::scanner::diagnostic::Code]
 macro_rules! err {
-    ($fmt:expr) => { Err(pai_error::PError::Info(format!($fmt))) };
-    ($fmt:expr, $($args:tt)*) =>{ Err(pai_error::PError::Info(format!($fmt,$($args)*))) }
+    ($code:ident, $($fmt:tt)*) => {
+        Err($crate::scanner::diagnostic::Diagnostic::new(
+            $crate::scanner::diagnostic::Code::$code,
+            format!($($fmt)*),
+        ))
+    };
 }
 
 macro_rules! unit {
@@ -34,16 +41,59 @@ macro_rules! unit {
         Unit::Ident(Ident::new($ident))
     };
 
+    // PrivateName
+    (PrivateName: $ident:expr) => {
+        Unit::PrivateName(Ident::new($ident))
+    };
+
+    // Contextual keyword Ident
+    (Ident: $ident:expr, $contextual:ident) => {
+        Unit::Ident(Ident::new_contextual(
+            $ident,
+            $crate::scanner::contextual::Contextual::$contextual,
+        ))
+    };
+
     // Number
     (Number: $num:expr) => {
         Unit::Lit(Lit::Number($num))
     };
 
+    // BigInt
+    (BigInt: $num:expr) => {
+        Unit::Lit(Lit::BigInt($num))
+    };
+
     // String
     (String: $str:expr) => {
         Unit::Lit(Lit::String($str))
     };
 
+    // Template
+    (NoSubstitutionTemplate: $template:expr) => {
+        Unit::Lit(Lit::NoSubstitutionTemplate($template))
+    };
+
+    (TemplateHead: $template:expr) => {
+        Unit::Lit(Lit::TemplateHead($template))
+    };
+
+    (TemplateMiddle: $template:expr) => {
+        Unit::Lit(Lit::TemplateMiddle($template))
+    };
+
+    (TemplateTail: $template:expr) => {
+        Unit::Lit(Lit::TemplateTail($template))
+    };
+
+    // RegExp
+    (RegExp: $pattern:expr, $flags:expr) => {
+        Unit::Lit(Lit::RegExp {
+            pattern: $pattern,
+            flags: $flags,
+        })
+    };
+
     (LineComment: $comment:expr) => {
         Unit::Comment(Comment::Line($comment))
     };
@@ -52,6 +102,14 @@ macro_rules! unit {
         Unit::Comment(Comment::Block($comment))
     };
 
+    (HtmlComment: $comment:expr) => {
+        Unit::Comment(Comment::Html($comment))
+    };
+
+    (Hashbang: $comment:expr) => {
+        Unit::Comment(Comment::Hashbang($comment))
+    };
+
     // Punctuator
     ("!") => {
         Unit::Punctuator(Punctuator::Not)
@@ -0,0 +1,340 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// Arbitrary precision integer, value of the [BigInt literal][1]
+///
+/// Sign and magnitude, magnitude is little-endian `u32` limbs without high zero limbs,
+/// so that derived [Eq] and [Hash] compare values
+///
+/// [1]:https://tc39.es/ecma262/#sec-static-semantics-bigintvalue
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    limbs: Vec<u32>,
+}
+
+impl BigInt {
+    pub const ZERO: Self = Self {
+        negative: false,
+        limbs: Vec::new(),
+    };
+
+    /// Raw BigInt literal with the `n` suffix
+    /// - `123n` `1_000n` DecimalBigIntegerLiteral
+    /// - `0x1Fn` `0o17n` `0b11n` NonDecimalIntegerLiteral
+    pub fn from_literal(raw: &str) -> Option<Self> {
+        let raw = raw.strip_suffix('n')?;
+        let bytes = raw.as_bytes();
+
+        if bytes.len() > 1 && bytes[0] == b'0' {
+            match bytes[1] {
+                b'x' | b'X' => return Self::from_str_radix(&raw[2..], 16),
+                b'o' | b'O' => return Self::from_str_radix(&raw[2..], 8),
+                b'b' | b'B' => return Self::from_str_radix(&raw[2..], 2),
+                // leading zero is not allowed
+                _ => return None,
+            }
+        }
+
+        Self::from_str_radix(raw, 10)
+    }
+
+    /// Unsigned digits in `radix` (2..=36), numeric separator `_` is only allowed between digits
+    pub fn from_str_radix(digits: &str, radix: u32) -> Option<Self> {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+
+        let mut value = Self::ZERO;
+        let mut empty = true;
+        let mut separator = false;
+
+        for ch in digits.chars() {
+            if ch == '_' {
+                // leading or doubled separator
+                if empty || separator {
+                    return None
+                }
+                separator = true;
+                continue
+            }
+
+            let digit = ch.to_digit(radix)?;
+
+            value.mul_add_small(radix, digit);
+            empty = false;
+            separator = false;
+        }
+
+        // trailing separator
+        if empty || separator {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    /// Digits in `radix` (2..=36), lowercase, with `-` if negative
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+
+        if self.is_zero() {
+            return String::from("0")
+        }
+
+        let mut limbs = self.limbs.clone();
+        let mut digits = Vec::new();
+
+        while !limbs.is_empty() {
+            let digit = div_rem_small(&mut limbs, radix);
+            digits.push(char::from_digit(digit, radix).unwrap_or('0'));
+        }
+
+        if self.negative {
+            digits.push('-');
+        }
+
+        digits.iter().rev().collect()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Truncated division like `BigInt` `/` and `%`, None if divide by zero
+    /// - quotient rounds toward zero
+    /// - remainder has the sign of the dividend
+    pub fn div_rem(&self, rhs: &Self) -> Option<(Self, Self)> {
+        if rhs.is_zero() {
+            return None
+        }
+
+        let (quotient, remainder) = div_rem_mag(&self.limbs, &rhs.limbs);
+
+        Some((
+            Self::new(self.negative != rhs.negative, quotient),
+            Self::new(self.negative, remainder),
+        ))
+    }
+
+    fn new(negative: bool, mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+
+        Self {
+            negative: negative && !limbs.is_empty(),
+            limbs,
+        }
+    }
+
+    /// `self * mul + add` of magnitude
+    fn mul_add_small(&mut self, mul: u32, add: u32) {
+        let mut carry = add as u64;
+
+        for limb in self.limbs.iter_mut() {
+            let value = *limb as u64 * mul as u64 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+
+        if carry != 0 {
+            self.limbs.push(carry as u32)
+        }
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        let magnitude = value.unsigned_abs();
+
+        Self::new(value < 0, vec![magnitude as u32, (magnitude >> 32) as u32])
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_str_radix(10))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.limbs, &other.limbs),
+            (true, true) => cmp_mag(&other.limbs, &self.limbs),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.limbs.clone())
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: Self) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::new(self.negative, add_mag(&self.limbs, &rhs.limbs))
+        }
+
+        match cmp_mag(&self.limbs, &rhs.limbs) {
+            Ordering::Less => BigInt::new(rhs.negative, sub_mag(&rhs.limbs, &self.limbs)),
+            _ => BigInt::new(self.negative, sub_mag(&self.limbs, &rhs.limbs)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: Self) -> BigInt {
+        self + &-rhs
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: Self) -> BigInt {
+        BigInt::new(
+            self.negative != rhs.negative,
+            mul_mag(&self.limbs, &rhs.limbs),
+        )
+    }
+}
+
+fn cmp_mag(lhs: &[u32], rhs: &[u32]) -> Ordering {
+    lhs.len()
+        .cmp(&rhs.len())
+        .then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+}
+
+fn add_mag(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let (long, short) = if lhs.len() >= rhs.len() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+
+    let mut limbs = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0;
+
+    for (index, &limb) in long.iter().enumerate() {
+        let value = limb as u64 + *short.get(index).unwrap_or(&0) as u64 + carry;
+        limbs.push(value as u32);
+        carry = value >> 32;
+    }
+
+    if carry != 0 {
+        limbs.push(carry as u32)
+    }
+
+    limbs
+}
+
+/// `lhs - rhs`, `lhs` must not be less than `rhs`
+fn sub_mag(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut limbs = Vec::with_capacity(lhs.len());
+    let mut borrow = 0;
+
+    for (index, &limb) in lhs.iter().enumerate() {
+        let value = limb as i64 - *rhs.get(index).unwrap_or(&0) as i64 - borrow;
+
+        if value < 0 {
+            limbs.push((value + (1 << 32)) as u32);
+            borrow = 1;
+        } else {
+            limbs.push(value as u32);
+            borrow = 0;
+        }
+    }
+
+    limbs
+}
+
+fn mul_mag(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut limbs = vec![0u32; lhs.len() + rhs.len()];
+
+    for (i, &x) in lhs.iter().enumerate() {
+        let mut carry = 0u64;
+
+        for (j, &y) in rhs.iter().enumerate() {
+            let value = limbs[i + j] as u64 + x as u64 * y as u64 + carry;
+            limbs[i + j] = value as u32;
+            carry = value >> 32;
+        }
+
+        limbs[i + rhs.len()] = carry as u32;
+    }
+
+    limbs
+}
+
+/// Divide magnitude by `divisor` in place, return remainder
+fn div_rem_small(limbs: &mut Vec<u32>, divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+
+    for limb in limbs.iter_mut().rev() {
+        let value = remainder << 32 | *limb as u64;
+        *limb = (value / divisor as u64) as u32;
+        remainder = value % divisor as u64;
+    }
+
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+
+    remainder as u32
+}
+
+/// Binary long division of magnitude, `rhs` must not be zero
+fn div_rem_mag(lhs: &[u32], rhs: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let mut quotient = vec![0u32; lhs.len()];
+    let mut remainder: Vec<u32> = Vec::new();
+
+    for bit in (0..lhs.len() * 32).rev() {
+        // remainder = remainder << 1 | bit
+        let mut carry = lhs[bit / 32] >> (bit % 32) & 1;
+
+        for limb in remainder.iter_mut() {
+            let next = *limb >> 31;
+            *limb = *limb << 1 | carry;
+            carry = next;
+        }
+
+        if carry != 0 {
+            remainder.push(carry)
+        }
+
+        if cmp_mag(&remainder, rhs) != Ordering::Less {
+            remainder = sub_mag(&remainder, rhs);
+
+            while remainder.last() == Some(&0) {
+                remainder.pop();
+            }
+
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+
+    (quotient, remainder)
+}
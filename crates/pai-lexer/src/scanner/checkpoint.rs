@@ -0,0 +1,132 @@
+use pai_file::span::Pos;
+
+use crate::scanner::{goal::Goal, jsx::JsxMode, Scanner};
+
+/// Saved [Scanner] state, restore to backtrack after speculative parsing
+/// - `(a, b) => a` arrow function or parenthesized expression
+/// - `f<T>(x)` generic call or comparison
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    ptr: Pos,
+    lo: Pos,
+    hi: Pos,
+    braces: Braces,
+    goal: Goal,
+    new_line: bool,
+    trivia: bool,
+    jsx_mode: Option<JsxMode>,
+    /// Diagnostics reported after the checkpoint are dropped on restore
+    diagnostics: usize,
+}
+
+impl Checkpoint {
+    /// Byte position of the next unit
+    pub fn pos(&self) -> Pos {
+        self.ptr
+    }
+}
+
+/// Nested templates kept inline, deeper ones are rare and spilled to the heap
+const INLINE_BRACES: usize = 4;
+
+/// Template depth stack of a [Checkpoint], see [Scanner::braces]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Braces {
+    Inline(u8, [u32; INLINE_BRACES]),
+    Spilled(Vec<u32>),
+}
+
+impl Braces {
+    fn new(braces: &[u32]) -> Self {
+        if braces.len() > INLINE_BRACES {
+            return Self::Spilled(braces.to_vec())
+        }
+
+        let mut inline = [0; INLINE_BRACES];
+        inline[..braces.len()].copy_from_slice(braces);
+
+        Self::Inline(braces.len() as u8, inline)
+    }
+
+    fn as_slice(&self) -> &[u32] {
+        match self {
+            Self::Inline(len, braces) => &braces[..*len as usize],
+            Self::Spilled(braces) => braces,
+        }
+    }
+}
+
+impl<'s> Scanner<'s> {
+    /// Cheap to take, never allocate unless more than 4 nested templates
+    pub fn checkpoint(&self) -> Checkpoint {
+        let offset = |ptr: *const u8| unsafe { ptr.offset_from(self.start) as Pos };
+
+        Checkpoint {
+            ptr: offset(self.ptr),
+            lo: offset(self.lo),
+            hi: offset(self.hi),
+            braces: Braces::new(&self.braces),
+            goal: self.goal,
+            new_line: self.new_line,
+            trivia: self.trivia,
+            jsx_mode: self.jsx_mode,
+            diagnostics: self.diagnostics.len(),
+        }
+    }
+
+    /// Rewind to the checkpoint, which must be taken from this scanner
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        let Checkpoint {
+            ptr,
+            lo,
+            hi,
+            braces,
+            goal,
+            new_line,
+            trivia,
+            jsx_mode,
+            diagnostics,
+        } = checkpoint;
+
+        let len = self.src_len() as Pos;
+        assert!(
+            ptr <= len && lo <= len && hi <= len,
+            "Checkpoint out of source"
+        );
+
+        // SAFETY: positions are checked in the source
+        unsafe {
+            self.ptr = self.start.add(ptr as usize);
+            self.lo = self.start.add(lo as usize);
+            self.hi = self.start.add(hi as usize);
+        }
+
+        self.braces.clear();
+        self.braces.extend_from_slice(braces.as_slice());
+        self.goal = goal;
+        self.new_line = new_line;
+        self.trivia = trivia;
+        self.jsx_mode = jsx_mode;
+        self.diagnostics.truncate(diagnostics);
+    }
+
+    /// Resume at the start of a unit, with the state inferred from the units before it
+    /// - `braces` template depth stack, see [Scanner::braces]
+    /// - `new_line` whether a line terminator precedes the unit
+    pub fn resume(&mut self, pos: Pos, braces: Vec<u32>, goal: Goal, new_line: bool) {
+        assert!(pos as usize <= self.src_len(), "Resume out of source");
+
+        // SAFETY: pos is checked in the source
+        self.ptr = unsafe { self.start.add(pos as usize) };
+        self.lo = self.ptr;
+        self.hi = self.ptr;
+
+        self.braces = braces;
+        self.goal = goal;
+        self.new_line = new_line;
+    }
+
+    fn src_len(&self) -> usize {
+        unsafe { self.end.offset_from(self.start) as usize }
+    }
+}
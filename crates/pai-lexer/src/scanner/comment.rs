@@ -9,4 +9,11 @@ pub enum Comment<'s> {
     /// Multi line
     /// - /* */
     Block(&'s str),
+    /// [HTML-like](https://tc39.es/ecma262/#sec-html-like-comments), only in script
+    /// - <!--
+    /// - --> at the start of a line
+    Html(&'s str),
+    /// [Hashbang](https://tc39.es/ecma262/#sec-hashbang), only at the start of the source
+    /// - #!
+    Hashbang(&'s str),
 }
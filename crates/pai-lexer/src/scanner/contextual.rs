@@ -0,0 +1,100 @@
+// Contextual keyword, [IdentifierName][1] but not [ReservedWord][2], keyword in some syntactic context
+// - ECMAScript, e.g. `async` `of` `get` `set` `static` `as` `from` `target` `meta` `accessor`
+// - TypeScript, e.g. `satisfies` `type` `keyof` `readonly` `number`
+// - Reserved in strict mode code only, e.g. `interface` `implements` `private` `package`
+//
+// [1]:https://tc39.es/ecma262/#prod-IdentifierName
+// [2]:https://tc39.es/ecma262/#prod-ReservedWord
+
+declare_enum![
+    NAME: Contextual,
+    Abstract = "abstract",
+    Accessor = "accessor",
+    Any = "any",
+    As = "as",
+    Asserts = "asserts",
+    Async = "async",
+    Bigint = "bigint",
+    Boolean = "boolean",
+    Constructor = "constructor",
+    Declare = "declare",
+    From = "from",
+    Get = "get",
+    Global = "global",
+    Implements = "implements",
+    Infer = "infer",
+    Interface = "interface",
+    Intrinsic = "intrinsic",
+    Is = "is",
+    Keyof = "keyof",
+    Meta = "meta",
+    Module = "module",
+    Namespace = "namespace",
+    Never = "never",
+    Number = "number",
+    Object = "object",
+    Of = "of",
+    Out = "out",
+    Override = "override",
+    Package = "package",
+    Private = "private",
+    Protected = "protected",
+    Public = "public",
+    Readonly = "readonly",
+    Require = "require",
+    Satisfies = "satisfies",
+    Set = "set",
+    Static = "static",
+    String = "string",
+    Symbol = "symbol",
+    Target = "target",
+    Type = "type",
+    Undefined = "undefined",
+    Unique = "unique",
+    Unknown = "unknown",
+];
+
+pub const ABSTRACT: &str = "abstract";
+pub const ACCESSOR: &str = "accessor";
+pub const ANY: &str = "any";
+pub const AS: &str = "as";
+pub const ASSERTS: &str = "asserts";
+pub const ASYNC: &str = "async";
+pub const BIGINT: &str = "bigint";
+pub const BOOLEAN: &str = "boolean";
+pub const CONSTRUCTOR: &str = "constructor";
+pub const DECLARE: &str = "declare";
+pub const FROM: &str = "from";
+pub const GET: &str = "get";
+pub const GLOBAL: &str = "global";
+pub const IMPLEMENTS: &str = "implements";
+pub const INFER: &str = "infer";
+pub const INTERFACE: &str = "interface";
+pub const INTRINSIC: &str = "intrinsic";
+pub const IS: &str = "is";
+pub const KEYOF: &str = "keyof";
+pub const META: &str = "meta";
+pub const MODULE: &str = "module";
+pub const NAMESPACE: &str = "namespace";
+pub const NEVER: &str = "never";
+pub const NUMBER: &str = "number";
+pub const OBJECT: &str = "object";
+pub const OF: &str = "of";
+pub const OUT: &str = "out";
+pub const OVERRIDE: &str = "override";
+pub const PACKAGE: &str = "package";
+pub const PRIVATE: &str = "private";
+pub const PROTECTED: &str = "protected";
+pub const PUBLIC: &str = "public";
+pub const READONLY: &str = "readonly";
+pub const REQUIRE: &str = "require";
+pub const SATISFIES: &str = "satisfies";
+pub const SET: &str = "set";
+pub const STATIC: &str = "static";
+pub const STRING: &str = "string";
+pub const SYMBOL: &str = "symbol";
+pub const TARGET: &str = "target";
+pub const TYPE: &str = "type";
+pub const UNDEFINED: &str = "undefined";
+pub const UNIQUE: &str = "unique";
+pub const UNKNOWN: &str = "unknown";
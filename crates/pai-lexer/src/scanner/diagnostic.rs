@@ -0,0 +1,61 @@
+use std::fmt;
+
+use pai_file::span::Span;
+
+pub type LexResult<T> = Result<T, Diagnostic>;
+
+/// Lexical error code of [Diagnostic]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Code {
+    /// Char can not start any unit
+    InvalidChar,
+    /// e.g. `..`
+    InvalidPunctuator,
+    /// Numeric separator, exponent part or BigInt with leading zero
+    InvalidNumber,
+    /// Escape sequence in string literal or identifier
+    InvalidEscape,
+    /// `#` followed by whitespace
+    InvalidPrivateName,
+    /// `#!` not at the start of the source
+    InvalidHashbang,
+    /// `>` `}` in JSXText
+    InvalidJsxText,
+    InvalidRegExpFlag,
+    UnterminatedComment,
+    UnterminatedString,
+    UnterminatedTemplate,
+    UnterminatedRegExp,
+}
+
+/// Lexical error, reported by [Scanner](crate::scanner::Scanner) with an [Error][1] unit covering the invalid text
+///
+/// [1]:crate::scanner::unit::Unit::Error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub code: Code,
+    /// Byte range of the error unit
+    pub span: Span,
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Span is unknown until the error unit is finished
+    pub fn new(code: Code, message: String) -> Self {
+        Self {
+            code,
+            span: Span::DUMMY,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} ({}..{}): {}",
+            self.code, self.span.lo, self.span.hi, self.message
+        )
+    }
+}
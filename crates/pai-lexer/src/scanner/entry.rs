@@ -1,7 +1,8 @@
-use pai_error::PResult;
-
 use crate::scanner::{
     comment::Comment,
+    contextual,
+    diagnostic::LexResult,
+    goal::{Goal, SourceType},
     helpers::is::{Radix, Unicode},
     ident::Ident,
     keyword::{self, Keyword},
@@ -11,7 +12,7 @@ use crate::scanner::{
     Scanner,
 };
 
-pub type Entry = for<'s> fn(&mut Scanner<'s>) -> PResult<Unit<'s>>;
+pub type Entry = for<'s> fn(&mut Scanner<'s>) -> LexResult<Unit<'s>>;
 
 pub fn lookup(index: u8) -> &'static Entry {
     &ENTRY_LOOKUP_TABLE[index as usize]
@@ -46,21 +47,34 @@ const ___: Entry = |_| unreachable!("Invalid UTF8 lead byte");
 
 /// Error
 const ERR: Entry = |sn: &mut Scanner| {
+    let byte = sn.byte();
     sn.skip(1);
 
-    err!("Invalid char '{}'", char!(sn.byte() as u32))
+    err!(InvalidChar, "Invalid char '{}'", byte.escape_ascii())
 };
 
-fn ident_word<'s>(sn: &mut Scanner<'s>) -> &'s str {
+fn ident_word<'s>(sn: &mut Scanner<'s>) -> LexResult<&'s str> {
     sn.mark();
     sn.skip(1);
-    sn.scan_ident_part();
+    sn.scan_ident_rest()?;
     sn.down();
-    sn.raw()
+
+    Ok(sn.raw())
+}
+
+/// [HTML-like Comments](https://tc39.es/ecma262/#sec-html-like-comments) in script
+/// - `<!--` `-->` skip `len` bytes then as single line comment
+fn html_comment<'s>(sn: &mut Scanner<'s>, len: usize) -> Unit<'s> {
+    sn.skip(len);
+    sn.mark();
+    sn.scan_line();
+    sn.down();
+
+    unit!(HtmlComment: sn.raw())
 }
 
 /// Ident
-const IDT: Entry = |sn: &mut Scanner| Ok(unit!(Ident: ident_word(sn)));
+const IDT: Entry = |sn: &mut Scanner| Ok(unit!(Ident: ident_word(sn)?));
 
 /// Exclamation
 /// - `!`
@@ -84,10 +98,50 @@ const DQT: Entry = |sn: &mut Scanner| sn.scan_string(b'"');
 
 /// Hash
 /// - `#`
+/// - `#name` [PrivateIdentifier](https://tc39.es/ecma262/#prod-PrivateIdentifier)
 const HSH: Entry = |sn: &mut Scanner| {
     sn.skip(1);
+    sn.mark();
+
+    let ident_start = match sn.byte() {
+        b'a'..=b'z' | b'A'..=b'Z' | b'$' | b'_' => {
+            sn.skip(1);
+            true
+        },
+        b'\\' => sn.scan_ident_escape(true),
+        0x80.. if sn.char().is_ident_start() => {
+            sn.skip_char();
+            true
+        },
+        _ => false,
+    };
+
+    if ident_start {
+        sn.scan_ident_rest()?;
+        sn.down();
+
+        return Ok(unit!(PrivateName: sn.raw()))
+    }
+
+    if sn.byte() == b'!' {
+        sn.skip(1);
+
+        return err!(
+            InvalidHashbang,
+            "Hashbang is only allowed at the start of the source"
+        )
+    }
+
+    let ch = sn.char();
 
-    Ok(unit!("#"))
+    if sn.is_empty() || ch.is_ascii_whitespace() || ch.is_space() || ch.is_line_terminator() {
+        err!(
+            InvalidPrivateName,
+            "Invalid private name, '#' must be followed by IdentifierName"
+        )
+    } else {
+        Ok(unit!("#"))
+    }
 };
 
 /// Dollar
@@ -193,6 +247,15 @@ const CMA: Entry = |sn: &mut Scanner| {
 /// Minus
 /// - `-`
 const MIS: Entry = |sn: &mut Scanner| {
+    // SingleLineHTMLCloseComment only at the start of a line
+    if sn.source_type == SourceType::Script
+        && (sn.new_line || sn.lo == sn.start)
+        && sn.peek(1) == b'-'
+        && sn.peek(2) == b'>'
+    {
+        return Ok(html_comment(sn, 3))
+    }
+
     sn.skip(1);
 
     if sn.eat(b'-') {
@@ -216,7 +279,7 @@ const DOT: Entry = |sn: &mut Scanner| {
         if sn.eat(b'.') {
             Ok(unit!("..."))
         } else {
-            err!("Invalid punctuator '..'")
+            err!(InvalidPunctuator, "Invalid punctuator '..'")
         }
     } else {
         if sn.byte().is_ascii_digit() {
@@ -224,6 +287,7 @@ const DOT: Entry = |sn: &mut Scanner| {
 
             sn.scan_decimal(false)?;
 
+            sn.scan_numeric_end()?;
             sn.down();
 
             Ok(unit!(Number: sn.raw()))
@@ -235,6 +299,7 @@ const DOT: Entry = |sn: &mut Scanner| {
 
 /// Slash
 /// - `/`
+/// - `/pattern/flags` if [Goal::RegExp]
 const SLH: Entry = |sn: &mut Scanner| {
     sn.skip(1);
 
@@ -252,6 +317,10 @@ const SLH: Entry = |sn: &mut Scanner| {
         return sn.scan_block_comment()
     }
 
+    if sn.goal == Goal::RegExp {
+        return sn.scan_regexp()
+    }
+
     if sn.eat(b'=') {
         Ok(unit!("/="))
     } else {
@@ -265,27 +334,60 @@ const ZRO: Entry = |sn: &mut Scanner| {
     sn.mark();
     sn.skip(1);
 
-    match sn.byte() {
+    let bigint = match sn.byte() {
         b'x' | b'X' => {
             sn.skip(1);
-            sn.scan_radix_int(Radix::Hex)?;
+            sn.scan_radix_int(Radix::Hex)?
         },
         b'o' | b'O' => {
             sn.skip(1);
-            sn.scan_radix_int(Radix::Oct)?;
+            sn.scan_radix_int(Radix::Oct)?
         },
         b'b' | b'B' => {
             sn.skip(1);
-            sn.scan_radix_int(Radix::Bin)?;
+            sn.scan_radix_int(Radix::Bin)?
         },
-        _ => {
-            sn.scan_decimal(true)?;
+        // LegacyOctalIntegerLiteral or NonOctalDecimalIntegerLiteral, no separator
+        b'0'..=b'9' | b'_' => {
+            let mut octal = true;
+
+            while sn.byte().is_ascii_digit() {
+                octal &= sn.byte() <= b'7';
+                sn.skip(1)
+            }
+
+            if sn.byte() == b'_' {
+                sn.skip_invalid_numeric();
+
+                return err!(InvalidNumber, "Numeric separator after leading zero")
+            }
+
+            // LegacyOctalIntegerLiteral takes no fraction nor exponent, e.g. `07.toString()`
+            let bigint = if octal {
+                sn.eat(b'n')
+            } else {
+                sn.scan_decimal(true)?
+            };
+
+            if bigint {
+                sn.skip_invalid_numeric();
+
+                return err!(InvalidNumber, "Invalid BigInt literal with leading zero")
+            }
+
+            false
         },
+        _ => sn.scan_decimal(true)?,
     };
 
+    sn.scan_numeric_end()?;
     sn.down();
 
-    Ok(unit!(Number: sn.raw()))
+    if bigint {
+        Ok(unit!(BigInt: sn.raw()))
+    } else {
+        Ok(unit!(Number: sn.raw()))
+    }
 };
 
 /// Digit
@@ -295,10 +397,17 @@ const ZRO: Entry = |sn: &mut Scanner| {
 const DIG: Entry = |sn: &mut Scanner| {
     sn.mark();
     sn.skip(1);
-    sn.scan_decimal(true)?;
+
+    let bigint = sn.scan_decimal(true)?;
+
+    sn.scan_numeric_end()?;
     sn.down();
 
-    Ok(unit!(Number: sn.raw()))
+    if bigint {
+        Ok(unit!(BigInt: sn.raw()))
+    } else {
+        Ok(unit!(Number: sn.raw()))
+    }
 };
 
 /// Colon
@@ -320,6 +429,15 @@ const SMI: Entry = |sn: &mut Scanner| {
 /// Less than
 /// - `<`
 const LST: Entry = |sn: &mut Scanner| {
+    // SingleLineHTMLOpenComment
+    if sn.source_type == SourceType::Script
+        && sn.peek(1) == b'!'
+        && sn.peek(2) == b'-'
+        && sn.peek(3) == b'-'
+    {
+        return Ok(html_comment(sn, 4))
+    }
+
     sn.skip(1);
 
     if sn.eat(b'<') {
@@ -426,10 +544,21 @@ const OBT: Entry = |sn: &mut Scanner| {
 ///
 /// [UnicodeEscapeSequence](https://tc39.es/ecma262/#prod-UnicodeEscapeSequence) Ident start
 const BSH: Entry = |sn: &mut Scanner| {
-    sn.skip(1);
+    sn.mark();
+
+    if sn.scan_ident_escape(true) {
+        sn.scan_ident_rest()?;
+        sn.down();
+
+        Ok(unit!(Ident: sn.raw()))
+    } else {
+        sn.skip_invalid_ident();
 
-    // !SPEC
-    err!("Escape unicode Ident Non supported")
+        err!(
+            InvalidEscape,
+            "Invalid Unicode escape sequence in identifier"
+        )
+    }
 };
 
 /// Closing bracket
@@ -458,9 +587,11 @@ const UDL: Entry = IDT;
 
 /// Grave accent
 /// - `
-const GAC: Entry = |_sn: &mut Scanner| {
-    // template literal
-    todo!()
+///
+/// [Template Literal](https://tc39.es/ecma262/#sec-template-literal-lexical-components)
+const GAC: Entry = |sn: &mut Scanner| {
+    sn.skip(1);
+    sn.scan_template(true)
 };
 
 /// Opening brace
@@ -468,6 +599,10 @@ const GAC: Entry = |_sn: &mut Scanner| {
 const OBE: Entry = |sn: &mut Scanner| {
     sn.skip(1);
 
+    if let Some(depth) = sn.braces.last_mut() {
+        *depth += 1
+    }
+
     Ok(unit!("{"))
 };
 
@@ -493,10 +628,21 @@ const VLN: Entry = |sn: &mut Scanner| {
 
 /// Closing brace
 /// - `}`
+/// - `}...` TemplateMiddle or TemplateTail if closing a substitution
 const CBE: Entry = |sn: &mut Scanner| {
     sn.skip(1);
 
-    Ok(unit!("}"))
+    match sn.braces.last_mut() {
+        Some(0) => {
+            sn.braces.pop();
+            sn.scan_template(false)
+        },
+        Some(depth) => {
+            *depth -= 1;
+            Ok(unit!("}"))
+        },
+        None => Ok(unit!("}")),
+    }
 };
 
 /// Tilde
@@ -507,45 +653,55 @@ const TID: Entry = |sn: &mut Scanner| {
     Ok(unit!("~"))
 };
 
-/// Keyword or Ident
+/// Keyword, contextual keyword or Ident
 /// - prefix with `a`..`z`
 const _A_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::AWAIT => unit!("await"),
+        ident @ contextual::ABSTRACT => unit!(Ident: ident, Abstract),
+        ident @ contextual::ACCESSOR => unit!(Ident: ident, Accessor),
+        ident @ contextual::ANY => unit!(Ident: ident, Any),
+        ident @ contextual::AS => unit!(Ident: ident, As),
+        ident @ contextual::ASSERTS => unit!(Ident: ident, Asserts),
+        ident @ contextual::ASYNC => unit!(Ident: ident, Async),
         ident => unit!(Ident: ident),
     })
 };
 
 const _B_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::BREAK => unit!("break"),
+        ident @ contextual::BIGINT => unit!(Ident: ident, Bigint),
+        ident @ contextual::BOOLEAN => unit!(Ident: ident, Boolean),
         ident => unit!(Ident: ident),
     })
 };
 
 const _C_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::CASE => unit!("case"),
         keyword::CATCH => unit!("catch"),
         keyword::CLASS => unit!("class"),
         keyword::CONST => unit!("const"),
         keyword::CONTINUE => unit!("continue"),
+        ident @ contextual::CONSTRUCTOR => unit!(Ident: ident, Constructor),
         ident => unit!(Ident: ident),
     })
 };
 
 const _D_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::DEBUGGER => unit!("debugger"),
         keyword::DEFAULT => unit!("default"),
         keyword::DELETE => unit!("delete"),
         keyword::DO => unit!("do"),
+        ident @ contextual::DECLARE => unit!(Ident: ident, Declare),
         ident => unit!(Ident: ident),
     })
 };
 
 const _E_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::ELSE => unit!("else"),
         keyword::ENUM => unit!("enum"),
         keyword::EXPORT => unit!("export"),
@@ -555,84 +711,144 @@ const _E_: Entry = |sn: &mut Scanner| {
 };
 
 const _F_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::FALSE => unit!("false"),
         keyword::FINALLY => unit!("finally"),
         keyword::FOR => unit!("for"),
         keyword::FUNCTION => unit!("function"),
+        ident @ contextual::FROM => unit!(Ident: ident, From),
         ident => unit!(Ident: ident),
     })
 };
 
-const _G_: Entry = IDT;
+const _G_: Entry = |sn: &mut Scanner| {
+    Ok(match ident_word(sn)? {
+        ident @ contextual::GET => unit!(Ident: ident, Get),
+        ident @ contextual::GLOBAL => unit!(Ident: ident, Global),
+        ident => unit!(Ident: ident),
+    })
+};
 
 const _H_: Entry = IDT;
 
 const _I_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::IF => unit!("if"),
         keyword::IMPORT => unit!("import"),
         keyword::IN => unit!("in"),
         keyword::INSTANCEOF => unit!("instanceof"),
+        ident @ contextual::IMPLEMENTS => unit!(Ident: ident, Implements),
+        ident @ contextual::INFER => unit!(Ident: ident, Infer),
+        ident @ contextual::INTERFACE => unit!(Ident: ident, Interface),
+        ident @ contextual::INTRINSIC => unit!(Ident: ident, Intrinsic),
+        ident @ contextual::IS => unit!(Ident: ident, Is),
         ident => unit!(Ident: ident),
     })
 };
 
 const _J_: Entry = IDT;
 
-const _K_: Entry = IDT;
+const _K_: Entry = |sn: &mut Scanner| {
+    Ok(match ident_word(sn)? {
+        ident @ contextual::KEYOF => unit!(Ident: ident, Keyof),
+        ident => unit!(Ident: ident),
+    })
+};
 
 const _L_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::LET => unit!("let"),
         ident => unit!(Ident: ident),
     })
 };
 
-const _M_: Entry = IDT;
+const _M_: Entry = |sn: &mut Scanner| {
+    Ok(match ident_word(sn)? {
+        ident @ contextual::META => unit!(Ident: ident, Meta),
+        ident @ contextual::MODULE => unit!(Ident: ident, Module),
+        ident => unit!(Ident: ident),
+    })
+};
 
 const _N_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::NEW => unit!("new"),
         keyword::NULL => unit!("null"),
+        ident @ contextual::NAMESPACE => unit!(Ident: ident, Namespace),
+        ident @ contextual::NEVER => unit!(Ident: ident, Never),
+        ident @ contextual::NUMBER => unit!(Ident: ident, Number),
+        ident => unit!(Ident: ident),
+    })
+};
+
+const _O_: Entry = |sn: &mut Scanner| {
+    Ok(match ident_word(sn)? {
+        ident @ contextual::OBJECT => unit!(Ident: ident, Object),
+        ident @ contextual::OF => unit!(Ident: ident, Of),
+        ident @ contextual::OUT => unit!(Ident: ident, Out),
+        ident @ contextual::OVERRIDE => unit!(Ident: ident, Override),
+        ident => unit!(Ident: ident),
+    })
+};
+
+const _P_: Entry = |sn: &mut Scanner| {
+    Ok(match ident_word(sn)? {
+        ident @ contextual::PACKAGE => unit!(Ident: ident, Package),
+        ident @ contextual::PRIVATE => unit!(Ident: ident, Private),
+        ident @ contextual::PROTECTED => unit!(Ident: ident, Protected),
+        ident @ contextual::PUBLIC => unit!(Ident: ident, Public),
         ident => unit!(Ident: ident),
     })
 };
 
-const _O_: Entry = IDT;
-const _P_: Entry = IDT;
 const _Q_: Entry = IDT;
 
 const _R_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::RETURN => unit!("return"),
+        ident @ contextual::READONLY => unit!(Ident: ident, Readonly),
+        ident @ contextual::REQUIRE => unit!(Ident: ident, Require),
         ident => unit!(Ident: ident),
     })
 };
 
 const _S_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::SUPER => unit!("super"),
         keyword::SWITCH => unit!("switch"),
+        ident @ contextual::SATISFIES => unit!(Ident: ident, Satisfies),
+        ident @ contextual::SET => unit!(Ident: ident, Set),
+        ident @ contextual::STATIC => unit!(Ident: ident, Static),
+        ident @ contextual::STRING => unit!(Ident: ident, String),
+        ident @ contextual::SYMBOL => unit!(Ident: ident, Symbol),
         ident => unit!(Ident: ident),
     })
 };
 
 const _T_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::THIS => unit!("this"),
         keyword::THROW => unit!("throw"),
         keyword::TRUE => unit!("true"),
         keyword::TRY => unit!("try"),
         keyword::TYPEOF => unit!("typeof"),
+        ident @ contextual::TARGET => unit!(Ident: ident, Target),
+        ident @ contextual::TYPE => unit!(Ident: ident, Type),
         ident => unit!(Ident: ident),
     })
 };
 
-const _U_: Entry = IDT;
+const _U_: Entry = |sn: &mut Scanner| {
+    Ok(match ident_word(sn)? {
+        ident @ contextual::UNDEFINED => unit!(Ident: ident, Undefined),
+        ident @ contextual::UNIQUE => unit!(Ident: ident, Unique),
+        ident @ contextual::UNKNOWN => unit!(Ident: ident, Unknown),
+        ident => unit!(Ident: ident),
+    })
+};
 
 const _V_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::VAR => unit!("var"),
         keyword::VOID => unit!("void"),
         ident => unit!(Ident: ident),
@@ -640,7 +856,7 @@ const _V_: Entry = |sn: &mut Scanner| {
 };
 
 const _W_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::WHILE => unit!("while"),
         keyword::WITH => unit!("with"),
         ident => unit!(Ident: ident),
@@ -650,7 +866,7 @@ const _W_: Entry = |sn: &mut Scanner| {
 const _X_: Entry = IDT;
 
 const _Y_: Entry = |sn: &mut Scanner| {
-    Ok(match ident_word(sn) {
+    Ok(match ident_word(sn)? {
         keyword::YIELD => unit!("yield"),
         ident => unit!(Ident: ident),
     })
@@ -663,12 +879,13 @@ const UID: Entry = |sn: &mut Scanner| {
     if sn.char().is_ident_part() {
         sn.mark();
         sn.skip_char();
-        sn.scan_ident_part();
+        sn.scan_ident_rest()?;
         sn.down();
         Ok(unit!(Ident: sn.raw()))
     } else {
+        let ch = sn.char();
         sn.skip_char();
 
-        err!("Invalid Unicode char")
+        err!(InvalidChar, "Invalid char '{ch}'")
     }
 };
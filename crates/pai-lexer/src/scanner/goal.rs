@@ -0,0 +1,60 @@
+use crate::scanner::{keyword::Keyword, lit::Lit, punctuator::Punctuator, unit::Unit};
+
+/// [Lexical goal symbol][1] of the next unit, decide how `/` is scanned
+///
+/// [1]:https://tc39.es/ecma262/#sec-ecmascript-language-lexical-grammar
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    /// InputElementDiv, `/` is punctuator
+    Div,
+    /// InputElementRegExp, `/` starts regular expression literal
+    #[default]
+    RegExp,
+}
+
+/// Goal symbol of the source text
+///
+/// [Script](https://tc39.es/ecma262/#prod-Script) or [Module](https://tc39.es/ecma262/#prod-Module)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SourceType {
+    /// Annex B [HTML-like Comments](https://tc39.es/ecma262/#sec-html-like-comments) are allowed
+    Script,
+    #[default]
+    Module,
+}
+
+impl Goal {
+    /// Infer goal of the unit after `unit`, None if `unit` is comment, JSX, trivia or error
+    ///
+    /// Heuristic only, parser should [set the goal][crate::scanner::Scanner::set_goal] if ambiguous,
+    /// e.g. `)` in `if (x) /re/.test(y)`
+    pub fn next(unit: &Unit) -> Option<Self> {
+        let goal = match unit {
+            Unit::Punctuator(punctuator) => {
+                match punctuator {
+                    Punctuator::RParen
+                    | Punctuator::RBracket
+                    | Punctuator::RBrace
+                    | Punctuator::BitInc
+                    | Punctuator::BitDec => Self::Div,
+                    _ => Self::RegExp,
+                }
+            },
+            Unit::Keyword(keyword) => {
+                match keyword {
+                    Keyword::This
+                    | Keyword::Super
+                    | Keyword::Null
+                    | Keyword::True
+                    | Keyword::False => Self::Div,
+                    _ => Self::RegExp,
+                }
+            },
+            Unit::Lit(Lit::TemplateHead(_) | Lit::TemplateMiddle(_)) => Self::RegExp,
+            Unit::Ident(_) | Unit::PrivateName(_) | Unit::Lit(_) => Self::Div,
+            Unit::Comment(_) | Unit::Jsx(_) | Unit::Trivia(_) | Unit::Error(_) => return None,
+        };
+
+        Some(goal)
+    }
+}
@@ -1,27 +1,66 @@
-use pai_error::PResult;
+use std::cell::Cell;
 
-use crate::scanner::{comment::Comment, unit::Unit, Scanner};
+use crate::scanner::{
+    comment::Comment,
+    diagnostic::LexResult,
+    helpers::{is::Unicode, simd::eq},
+    unit::Unit,
+    Scanner,
+};
 
 impl<'s> Scanner<'s> {
-    pub fn scan_block_comment(&mut self) -> PResult<Unit<'s>> {
+    pub fn scan_block_comment(&mut self) -> LexResult<Unit<'s>> {
         self.mark();
 
-        while !self.is_empty() {
-            if self.byte() == b'*' {
-                if self.peek(1) == b'/' {
+        loop {
+            self.skip_comment_chunks();
+
+            if self.is_empty() {
+                break
+            }
+
+            match self.byte() {
+                b'*' if self.peek(1) == b'/' => {
                     self.down();
 
                     self.skip(2);
 
                     return Ok(unit!(BlockComment: self.raw()))
-                } else {
-                    self.skip(1)
-                }
+                },
+                b'\n' | b'\r' => self.new_line = true,
+                0xE2 if self.char().is_line_terminator() => self.new_line = true,
+                _ => {},
             }
 
             self.skip_char()
         }
 
-        err!("Unterminated block comment")
+        err!(UnterminatedComment, "Unterminated block comment")
+    }
+
+    /// Fast path to `*` or U+2028 U+2029 lead byte, LF and CR on the way are recorded
+    fn skip_comment_chunks(&mut self) {
+        let new_line = Cell::new(false);
+
+        self.skip_chunks(|chunk| {
+            let stop = eq(chunk, b'*') | eq(chunk, 0xE2);
+            let line = (eq(chunk, b'\n') | eq(chunk, b'\r')).to_bitmask();
+
+            // line terminators before the stop byte
+            let line = match stop.to_bitmask() {
+                0 => line,
+                bits => line & ((1 << bits.trailing_zeros()) - 1),
+            };
+
+            if line != 0 {
+                new_line.set(true)
+            }
+
+            stop
+        });
+
+        if new_line.get() {
+            self.new_line = true
+        }
     }
 }
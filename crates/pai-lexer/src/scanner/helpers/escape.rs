@@ -0,0 +1,220 @@
+use std::borrow::Cow;
+
+use crate::scanner::{helpers::is::Unicode, Scanner};
+
+/// Scanned [EscapeSequence][1]
+///
+/// [1]:https://tc39.es/ecma262/#prod-EscapeSequence
+#[derive(Debug, Clone, Copy)]
+pub enum Escape {
+    /// Escaped code point
+    Char(char),
+    /// [LineContinuation](https://tc39.es/ecma262/#prod-LineContinuation)
+    LineContinuation,
+    /// Strict mode error, NotEscapeSequence in template
+    /// - [LegacyOctalEscapeSequence](https://tc39.es/ecma262/#prod-LegacyOctalEscapeSequence)
+    /// - [NonOctalDecimalEscapeSequence](https://tc39.es/ecma262/#prod-NonOctalDecimalEscapeSequence)
+    Legacy(char),
+    /// [NotEscapeSequence](https://tc39.es/ecma262/#prod-NotEscapeSequence)
+    Invalid,
+}
+
+impl<'s> Scanner<'s> {
+    /// Scan escape sequence after `\`
+    ///
+    /// [Invalid][Escape::Invalid] escape skip at most the escape letter, the rest bytes remain as raw chars
+    pub fn scan_escape(&mut self) -> Escape {
+        let ch = match self.byte() {
+            b'\r' => {
+                self.skip(1);
+                self.eat(b'\n');
+
+                return Escape::LineContinuation
+            },
+            b'\n' => {
+                self.skip(1);
+
+                return Escape::LineContinuation
+            },
+            b'0' if !self.peek(1).is_ascii_digit() => '\0',
+            b'0'..=b'7' => return Escape::Legacy(self.scan_legacy_octal()),
+            b'8' | b'9' => {
+                let ch = self.byte() as char;
+                self.skip(1);
+
+                return Escape::Legacy(ch)
+            },
+            b'b' => '\u{0008}',
+            b'f' => '\u{000C}',
+            b'n' => '\n',
+            b'r' => '\r',
+            b't' => '\t',
+            b'v' => '\u{000B}',
+            b'x' => {
+                self.skip(1);
+
+                return match self.scan_hex_digits(2) {
+                    Some(code_point) => Escape::Char(char!(code_point)),
+                    None => Escape::Invalid,
+                }
+            },
+            b'u' => {
+                self.skip(1);
+
+                return match self.scan_unicode_escape() {
+                    Some(ch) => Escape::Char(ch),
+                    None => Escape::Invalid,
+                }
+            },
+            _ => {
+                let ch = self.char();
+                self.skip_char();
+
+                return if ch.is_line_terminator() {
+                    Escape::LineContinuation
+                } else {
+                    Escape::Char(ch)
+                }
+            },
+        };
+
+        self.skip(1);
+
+        Escape::Char(ch)
+    }
+
+    /// [LegacyOctalEscapeSequence][1]
+    /// - ZeroToThree OctalDigit? OctalDigit?
+    /// - FourToSeven OctalDigit?
+    ///
+    /// [1]:https://tc39.es/ecma262/#prod-LegacyOctalEscapeSequence
+    fn scan_legacy_octal(&mut self) -> char {
+        let max = if self.byte() <= b'3' { 3 } else { 2 };
+
+        let mut code_point = 0;
+        let mut count = 0;
+
+        while count < max && matches!(self.byte(), b'0'..=b'7') {
+            code_point = code_point << 3 | (self.byte() - b'0') as u32;
+            count += 1;
+            self.skip(1);
+        }
+
+        char!(code_point)
+    }
+
+    /// [UnicodeEscapeSequence][1] after `\u`
+    /// - Hex4Digits
+    /// - { CodePoint }
+    ///
+    /// Surrogate pair `\uD83D\uDE00` is combined, lone surrogate is replaced with `U+FFFD`
+    ///
+    /// [1]:https://tc39.es/ecma262/#prod-UnicodeEscapeSequence
+    pub fn scan_unicode_escape(&mut self) -> Option<char> {
+        let code_point = self.scan_code_point()?;
+
+        if !(0xD800..=0xDBFF).contains(&code_point) {
+            return Some(char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER))
+        }
+
+        // trail surrogate
+        if self.byte() == b'\\' && self.peek(1) == b'u' {
+            let ptr = self.ptr;
+
+            self.skip(2);
+
+            match self.scan_code_point() {
+                Some(trail @ 0xDC00..=0xDFFF) => {
+                    let code_point = 0x10000 + ((code_point - 0xD800) << 10) + (trail - 0xDC00);
+
+                    return Some(char!(code_point))
+                },
+                _ => self.ptr = ptr,
+            }
+        }
+
+        Some(char::REPLACEMENT_CHARACTER)
+    }
+
+    /// Code point of a single UnicodeEscapeSequence after `\u`, never combined, skip nothing if failed
+    pub fn scan_code_point(&mut self) -> Option<u32> {
+        if self.byte() != b'{' {
+            return self.scan_hex_digits(4)
+        }
+
+        let mut code_point = 0;
+        let mut count = 0;
+
+        while let Some(digit) = (self.peek(count + 1) as char).to_digit(16) {
+            code_point = code_point << 4 | digit;
+
+            if code_point > 0x10FFFF {
+                return None
+            }
+
+            count += 1;
+        }
+
+        if count > 0 && self.peek(count + 1) == b'}' {
+            self.skip(count as usize + 2);
+
+            Some(code_point)
+        } else {
+            None
+        }
+    }
+
+    /// Scan exactly `count` hex digits, skip nothing if failed
+    pub fn scan_hex_digits(&mut self, count: isize) -> Option<u32> {
+        let mut value = 0;
+
+        for offset in 0..count {
+            value = value << 4 | (self.peek(offset) as char).to_digit(16)?;
+        }
+
+        self.skip(count as usize);
+
+        Some(value)
+    }
+}
+
+/// Cooked value builder, borrow the raw slice until the first escape
+#[derive(Debug, Default)]
+pub enum Cooked {
+    /// Same as the raw slice
+    #[default]
+    Raw,
+    /// Owned value since the first escape
+    Owned(String),
+    /// Contains [NotEscapeSequence](https://tc39.es/ecma262/#prod-NotEscapeSequence)
+    Invalid,
+}
+
+impl Cooked {
+    /// Take the raw slice scanned so far as owned value
+    pub fn own(&mut self, raw: &str) {
+        if let Self::Raw = self {
+            *self = Self::Owned(raw.to_owned())
+        }
+    }
+
+    pub fn push(&mut self, ch: char) {
+        if let Self::Owned(value) = self {
+            value.push(ch)
+        }
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        if let Self::Owned(value) = self {
+            value.push_str(s)
+        }
+    }
+
+    pub fn finish(self, raw: &str) -> Option<Cow<'_, str>> {
+        match self {
+            Self::Raw => Some(Cow::Borrowed(raw)),
+            Self::Owned(value) => Some(Cow::Owned(value)),
+            Self::Invalid => None,
+        }
+    }
+}
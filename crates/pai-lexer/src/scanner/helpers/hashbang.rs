@@ -1,7 +1,7 @@
 use crate::scanner::Scanner;
 
 impl<'s> Scanner<'s> {
-    /// Scan hashbang before iterate
+    /// Scan hashbang at the start of the source, see [Scanner::next_token]
     ///
     /// [Hashbang Comments](https://tc39.es/ecma262/#sec-hashbang)
     pub fn scan_hashbang(&mut self) -> Option<&'s str> {
@@ -1,11 +1,78 @@
-use crate::scanner::{helpers::is::Unicode, Scanner};
+use crate::scanner::{
+    diagnostic::LexResult,
+    helpers::{is::Unicode, simd::is_ascii_ident},
+    ident, Scanner,
+};
 
 impl<'s> Scanner<'s> {
     pub fn scan_ident_part(&mut self) {
+        // fast path of ASCII ident part
+        self.skip_chunks(|chunk| !is_ascii_ident(chunk));
+
         if let Some(handler) = IDENT_PART_LOOKUP_TABLE[self.byte() as usize] {
             handler(self)
         }
     }
+
+    /// Scan the rest of the IdentifierName, error if an invalid escape follows, e.g. `a\u{1F600}`
+    pub fn scan_ident_rest(&mut self) -> LexResult<()> {
+        self.scan_ident_part();
+
+        // valid escapes are skipped by the ident part
+        if self.byte() == b'\\' {
+            self.skip_invalid_ident();
+
+            return err!(
+                InvalidEscape,
+                "Invalid Unicode escape sequence in identifier"
+            )
+        }
+
+        Ok(())
+    }
+
+    /// Skip the invalid escapes and the rest of the identifier, so the error unit covers it all
+    pub fn skip_invalid_ident(&mut self) {
+        while self.eat(b'\\') {
+            if self.eat(b'u') && self.eat(b'{') {
+                while self.byte().is_ascii_alphanumeric() {
+                    self.skip(1)
+                }
+
+                self.eat(b'}');
+            }
+
+            // hex digits of `\uXXXX` are ident part
+            self.scan_ident_part();
+        }
+    }
+
+    /// Scan `\u` escaped IdentifierStart or IdentifierPart, skip nothing if failed
+    ///
+    /// Each escape is a code point on its own, surrogate `\uD835` is invalid even if paired
+    pub fn scan_ident_escape(&mut self, start: bool) -> bool {
+        let ptr = self.ptr;
+
+        if self.peek(1) == b'u' {
+            self.skip(2);
+
+            if let Some(ch) = self.scan_code_point().and_then(char::from_u32) {
+                let valid = if start {
+                    ident::Identifier::is_ident_start(&ch)
+                } else {
+                    ident::Identifier::is_ident_part(&ch)
+                };
+
+                if valid {
+                    return true
+                }
+            }
+        }
+
+        self.ptr = ptr;
+
+        false
+    }
 }
 
 type Handler = fn(&mut Scanner);
@@ -57,5 +124,8 @@ const UIP: Option<Handler> = Some(|sn: &mut Scanner| {
 /// - \u {CodePoint}
 ///
 /// [1]:https://tc39.es/ecma262/#prod-UnicodeEscapeSequence
-// !SPEC
-const BSH: Option<Handler> = None;
+const BSH: Option<Handler> = Some(|sn: &mut Scanner| {
+    if sn.scan_ident_escape(false) {
+        sn.scan_ident_part()
+    }
+});
@@ -0,0 +1,88 @@
+use crate::scanner::{
+    diagnostic::LexResult,
+    helpers::{escape::Cooked, is::Unicode},
+    jsx::{Jsx, JsxStr},
+    unit::Unit,
+    Scanner,
+};
+
+impl<'s> Scanner<'s> {
+    /// [JSXIdentifier](https://facebook.github.io/jsx/#prod-JSXIdentifier)
+    /// - IdentifierStart
+    /// - JSXIdentifier IdentifierPart
+    /// - JSXIdentifier `-`
+    pub fn scan_jsx_ident(&mut self) -> &'s str {
+        self.mark();
+        self.skip_char();
+
+        loop {
+            self.scan_ident_part();
+
+            if !self.eat(b'-') {
+                break
+            }
+        }
+
+        self.down();
+        self.raw()
+    }
+
+    pub fn is_jsx_ident_start(&self) -> bool {
+        match self.byte() {
+            b'a'..=b'z' | b'A'..=b'Z' | b'$' | b'_' => true,
+            0x80.. => self.char().is_ident_start(),
+            _ => false,
+        }
+    }
+
+    /// [JSXText](https://facebook.github.io/jsx/#prod-JSXText) until `{` `<` `>` `}`
+    pub fn scan_jsx_text(&mut self) -> Unit<'s> {
+        self.mark();
+
+        let cooked = self.scan_jsx_chars(|byte| matches!(byte, b'{' | b'<' | b'>' | b'}'));
+
+        self.down();
+
+        Unit::Jsx(Jsx::Text(JsxStr::new(self.raw(), cooked)))
+    }
+
+    /// [JSXString](https://facebook.github.io/jsx/#prod-JSXString) attribute value, `\` is not escape
+    pub fn scan_jsx_string(&mut self, quote: u8) -> LexResult<Unit<'s>> {
+        self.skip(1);
+        self.mark();
+
+        let cooked = self.scan_jsx_chars(|byte| byte == quote);
+
+        if self.is_empty() {
+            return err!(UnterminatedString, "Unterminated JSX string")
+        }
+
+        self.down();
+        self.skip(1);
+
+        Ok(Unit::Jsx(Jsx::String(JsxStr::new(self.raw(), cooked))))
+    }
+
+    /// Scan chars until `is_end` or end of source, decode HTML character references
+    fn scan_jsx_chars(&mut self, is_end: impl Fn(u8) -> bool) -> Cooked {
+        let mut cooked = Cooked::Raw;
+
+        while !self.is_empty() && !is_end(self.byte()) {
+            if self.byte() == b'&' {
+                self.down();
+
+                if let Some(ch) = self.scan_entity() {
+                    cooked.own(self.raw());
+                    cooked.push(ch);
+
+                    continue
+                }
+            }
+
+            cooked.push(self.char());
+            self.skip_char()
+        }
+
+        cooked
+    }
+}
@@ -1,7 +1,13 @@
-use crate::scanner::{helpers::is::Unicode, Scanner};
+use crate::scanner::{
+    helpers::{is::Unicode, simd::eq},
+    Scanner,
+};
 
 impl<'s> Scanner<'s> {
     pub fn scan_line(&mut self) {
+        // fast path to the line terminator or U+2028 U+2029 lead byte, never reach EOF
+        self.skip_chunks(|chunk| eq(chunk, b'\n') | eq(chunk, b'\r') | eq(chunk, 0xE2));
+
         LINE_TERMINATOR_LOOKUP_TABLE[self.byte() as usize](self)
     }
 }
@@ -31,8 +37,13 @@ const LINE_TERMINATOR_LOOKUP_TABLE: &[Handler; 256] = &[
 /// Unreachable
 const ___: Handler = |_| unreachable!("Invalid UTF8 lead byte");
 
-/// End of file
-const EOF: Handler = |_| {};
+/// End of file, or NUL in the line
+const EOF: Handler = |sn| {
+    if !sn.is_empty() {
+        sn.skip(1);
+        sn.scan_line()
+    }
+};
 
 /// New line
 const NLN: Handler = |_| {};
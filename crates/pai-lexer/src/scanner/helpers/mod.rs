@@ -1,9 +1,15 @@
 pub mod block_comment;
 pub mod char;
+pub mod entity;
+pub mod escape;
 pub mod hashbang;
 pub mod ident_part;
 pub mod is;
+pub mod jsx;
 pub mod line;
 pub mod number;
+pub mod regexp;
+pub mod simd;
 pub mod string;
+pub mod template;
 pub mod whitespace;
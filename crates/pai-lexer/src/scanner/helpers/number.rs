@@ -1,14 +1,13 @@
-use pai_error::PResult;
-
 use crate::scanner::{
-    helpers::is::{Digit, Radix},
+    diagnostic::LexResult,
+    helpers::is::{Digit, Radix, Unicode},
     Scanner,
 };
 
 impl<'s> Scanner<'s> {
     /// [DecimalDigit](https://tc39.es/ecma262/#prod-DecimalDigit)
 
-    pub fn scan_decimal(&mut self, allow_dot: bool) -> PResult<bool> {
+    pub fn scan_decimal(&mut self, allow_dot: bool) -> LexResult<bool> {
         let mut decimal_point = allow_dot;
         let mut exponent_part = true;
 
@@ -19,7 +18,9 @@ impl<'s> Scanner<'s> {
                     if self.peek(-1).is_ascii_digit() && self.peek(1).is_ascii_digit() {
                         self.skip(2)
                     } else {
-                        return err!("Invalid Numeric separator")
+                        self.skip_invalid_numeric();
+
+                        return err!(InvalidNumber, "Invalid Numeric separator")
                     }
                 },
                 b'.' if decimal_point => {
@@ -37,13 +38,23 @@ impl<'s> Scanner<'s> {
                     if self.byte().is_ascii_digit() {
                         self.skip(1)
                     } else {
-                        return err!("Invalid DecimalLiteral ExponentPart")
+                        self.skip_invalid_numeric();
+
+                        return err!(InvalidNumber, "Invalid DecimalLiteral ExponentPart")
                     }
                 },
                 b'n' if decimal_point && exponent_part => {
                     self.skip(1);
                     return Ok(true)
                 },
+                b'n' => {
+                    self.skip_invalid_numeric();
+
+                    return err!(
+                        InvalidNumber,
+                        "BigInt literal takes no fraction nor exponent"
+                    )
+                },
                 _ => return Ok(false),
             }
         }
@@ -54,7 +65,13 @@ impl<'s> Scanner<'s> {
     /// - Octal: `0[oO]` [0-7] _? [0-7] n?
     /// - Hex: `0[xX]` [0-F] _? [0-F] n?
 
-    pub fn scan_radix_int(&mut self, radix: Radix) -> PResult<bool> {
+    pub fn scan_radix_int(&mut self, radix: Radix) -> LexResult<bool> {
+        if !self.byte().is_digit(radix) {
+            self.skip_invalid_numeric();
+
+            return err!(InvalidNumber, "Missing digits after the radix prefix")
+        }
+
         loop {
             if self.byte().is_digit(radix) {
                 self.skip(1);
@@ -65,11 +82,47 @@ impl<'s> Scanner<'s> {
                 if self.peek(-1).is_digit(radix) && self.peek(1).is_digit(radix) {
                     self.skip(2);
                 } else {
-                    return err!("Invalid Numeric separator")
+                    self.skip_invalid_numeric();
+
+                    return err!(InvalidNumber, "Invalid Numeric separator")
                 }
             } else {
                 return Ok(self.eat(b'n'))
             }
         }
     }
+
+    /// The SourceCharacter immediately following a NumericLiteral must not be an
+    /// IdentifierStart or DecimalDigit, e.g. `3in` `0b12`
+    pub fn scan_numeric_end(&mut self) -> LexResult<()> {
+        let invalid = match self.byte() {
+            b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'$' | b'_' => true,
+            b'\\' => self.peek(1) == b'u',
+            0x80.. => self.char().is_ident_start(),
+            _ => false,
+        };
+
+        if invalid {
+            self.skip_invalid_numeric();
+
+            return err!(
+                InvalidNumber,
+                "Numeric literal must not be followed by IdentifierStart or digit"
+            )
+        }
+
+        Ok(())
+    }
+
+    /// Skip the rest of the invalid numeric literal, so the error unit covers it all
+    pub fn skip_invalid_numeric(&mut self) {
+        loop {
+            match self.byte() {
+                b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'$' | b'_' => self.skip(1),
+                b'\\' if self.scan_ident_escape(false) => {},
+                0x80.. if self.char().is_ident_part() => self.skip_char(),
+                _ => return,
+            }
+        }
+    }
 }
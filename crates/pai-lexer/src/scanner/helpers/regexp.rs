@@ -0,0 +1,106 @@
+use crate::scanner::{diagnostic::LexResult, helpers::is::Unicode, lit::Lit, unit::Unit, Scanner};
+
+impl<'s> Scanner<'s> {
+    /// [Regular Expression Literal](https://tc39.es/ecma262/#sec-literals-regular-expression-literals)
+    ///
+    /// Scan after the opening `/`
+    /// - `/` RegularExpressionBody `/` RegularExpressionFlags
+    pub fn scan_regexp(&mut self) -> LexResult<Unit<'s>> {
+        self.mark();
+
+        // inside RegularExpressionClass `[...]`, `/` is not terminator
+        let mut class = false;
+
+        loop {
+            if self.is_empty() {
+                return err!(UnterminatedRegExp, "Unterminated regular expression")
+            }
+
+            match self.byte() {
+                b'/' if !class => break,
+                b'\n' | b'\r' => return err!(UnterminatedRegExp, "Unterminated regular expression"),
+                b'[' => {
+                    class = true;
+                    self.skip(1)
+                },
+                b']' => {
+                    class = false;
+                    self.skip(1)
+                },
+                b'\\' => {
+                    self.skip(1);
+
+                    if self.is_empty() || matches!(self.byte(), b'\n' | b'\r') {
+                        return err!(UnterminatedRegExp, "Unterminated regular expression")
+                    }
+
+                    self.skip_char()
+                },
+                _ => {
+                    if self.char().is_line_terminator() {
+                        return err!(UnterminatedRegExp, "Unterminated regular expression")
+                    }
+
+                    self.skip_char()
+                },
+            }
+        }
+
+        self.down();
+        let pattern = self.raw();
+        self.skip(1);
+
+        self.mark();
+        self.scan_ident_part();
+        self.down();
+        let flags = self.raw();
+
+        Self::check_regexp_flags(flags)?;
+
+        Ok(unit!(RegExp: pattern, flags))
+    }
+
+    /// [RegularExpressionFlags](https://tc39.es/ecma262/#sec-parsepattern)
+    /// - `d` `g` `i` `m` `s` `u` `v` `y`, each at most once
+    /// - `u` and `v` are exclusive
+    fn check_regexp_flags(flags: &str) -> LexResult<()> {
+        let mut seen = 0u8;
+
+        for flag in flags.chars() {
+            let bit = match flag {
+                'd' => 1 << 0,
+                'g' => 1 << 1,
+                'i' => 1 << 2,
+                'm' => 1 << 3,
+                's' => 1 << 4,
+                'u' => 1 << 5,
+                'v' => 1 << 6,
+                'y' => 1 << 7,
+                _ => {
+                    return err!(
+                        InvalidRegExpFlag,
+                        "Invalid regular expression flag '{flag}'"
+                    )
+                },
+            };
+
+            if seen & bit != 0 {
+                return err!(
+                    InvalidRegExpFlag,
+                    "Duplicate regular expression flag '{flag}'"
+                )
+            }
+
+            seen |= bit;
+        }
+
+        if seen & (1 << 5 | 1 << 6) == 1 << 5 | 1 << 6 {
+            return err!(
+                InvalidRegExpFlag,
+                "Regular expression flags 'u' and 'v' are exclusive"
+            )
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use std::{
+    simd::{
+        cmp::{SimdPartialEq, SimdPartialOrd},
+        Mask, Simd,
+    },
+    slice,
+};
+
+use crate::scanner::Scanner;
+
+/// Bytes per chunk, SSE2 on x86_64 and NEON on aarch64, scalar on the other targets
+pub const LANES: usize = 16;
+
+pub type Chunk = Simd<u8, LANES>;
+
+pub type ChunkMask = Mask<i8, LANES>;
+
+impl<'s> Scanner<'s> {
+    /// Next [LANES] bytes, None if less left
+    pub fn chunk(&self) -> Option<Chunk> {
+        if self.len() < LANES {
+            return None
+        }
+
+        // SAFETY: LANES bytes are in the source
+        Some(Chunk::from_slice(unsafe {
+            slice::from_raw_parts(self.ptr, LANES)
+        }))
+    }
+
+    /// Skip whole chunks until the first byte matched by `stop`, return the skipped text
+    ///
+    /// `stop` must match ASCII bytes or UTF-8 lead bytes only, the rest less than [LANES] bytes
+    /// are left to the scalar path
+    pub fn skip_chunks(&mut self, stop: impl Fn(Chunk) -> ChunkMask) -> &'s str {
+        let from = self.ptr;
+
+        while let Some(chunk) = self.chunk() {
+            let mask = stop(chunk).to_bitmask();
+
+            if mask != 0 {
+                self.skip(mask.trailing_zeros() as usize);
+
+                return self.skipped(from)
+            }
+
+            self.skip(LANES)
+        }
+
+        // chunk boundary may split a char, back to its lead byte
+        while self.ptr > from && is_continuation(self.byte()) {
+            // SAFETY: ptr is after from
+            self.ptr = unsafe { self.ptr.sub(1) }
+        }
+
+        self.skipped(from)
+    }
+
+    fn skipped(&self, from: *const u8) -> &'s str {
+        // SAFETY: from and ptr are both on char boundary
+        unsafe { std::str::from_utf8_unchecked(slice::from_ptr_range(from..self.ptr)) }
+    }
+}
+
+pub fn splat(byte: u8) -> Chunk {
+    Chunk::splat(byte)
+}
+
+/// `byte` in the chunk
+pub fn eq(chunk: Chunk, byte: u8) -> ChunkMask {
+    chunk.simd_eq(splat(byte))
+}
+
+/// ASCII IdentifierPart
+/// - `0`..`9`
+/// - `a`..`z`
+/// - `A`..`Z`
+/// - `$`
+/// - `_`
+pub fn is_ascii_ident(chunk: Chunk) -> ChunkMask {
+    let lower = chunk | splat(0x20);
+
+    let alpha = lower.simd_ge(splat(b'a')) & lower.simd_le(splat(b'z'));
+    let digit = chunk.simd_ge(splat(b'0')) & chunk.simd_le(splat(b'9'));
+
+    alpha | digit | eq(chunk, b'$') | eq(chunk, b'_')
+}
+
+/// `10xxxxxx` UTF-8 continuation byte
+fn is_continuation(byte: u8) -> bool {
+    byte & 0xC0 == 0x80
+}
@@ -1,26 +1,80 @@
-use pai_error::PResult;
-
-use crate::scanner::{lit::Lit, unit::Unit, Scanner};
+use crate::scanner::{
+    diagnostic::LexResult,
+    helpers::{
+        escape::{Cooked, Escape},
+        simd::eq,
+    },
+    lit::{Lit, Str},
+    unit::Unit,
+    Scanner,
+};
 
 impl<'s> Scanner<'s> {
     /// [String Literal](https://tc39.es/ecma262/#sec-literals-string-literals)
-    pub fn scan_string(&mut self, quote: u8) -> PResult<Unit<'s>> {
+    pub fn scan_string(&mut self, quote: u8) -> LexResult<Unit<'s>> {
         self.skip(1);
         self.mark();
 
-        // !BUG
-        // String Literals unchecked
-        while self.byte() != quote {
-            if self.byte() == b'\\' {
-                self.skip(1)
+        let mut cooked = Cooked::Raw;
+        let mut legacy = false;
+        // report after the closing quote, so the error unit covers the whole literal
+        let mut invalid = None;
+
+        loop {
+            // fast path to the quote, escape or line terminator
+            let chars = self.skip_chunks(|chunk| {
+                eq(chunk, quote) | eq(chunk, b'\\') | eq(chunk, b'\n') | eq(chunk, b'\r')
+            });
+            cooked.push_str(chars);
+
+            if self.is_empty() {
+                return err!(UnterminatedString, "Unterminated string literal")
             }
 
-            self.skip_char()
+            match self.byte() {
+                byte if byte == quote => break,
+                // U+2028 and U+2029 are allowed, but not LF and CR
+                b'\n' | b'\r' => return err!(UnterminatedString, "Unterminated string literal"),
+                b'\\' => {
+                    self.down();
+                    cooked.own(self.raw());
+                    self.skip(1);
+
+                    if self.is_empty() {
+                        return err!(UnterminatedString, "Unterminated string literal")
+                    }
+
+                    let letter = self.byte();
+
+                    match self.scan_escape() {
+                        Escape::Char(ch) => cooked.push(ch),
+                        Escape::LineContinuation => {},
+                        Escape::Legacy(ch) => {
+                            legacy = true;
+                            cooked.push(ch)
+                        },
+                        Escape::Invalid if letter == b'x' => {
+                            invalid.get_or_insert("Invalid hexadecimal escape sequence");
+                        },
+                        Escape::Invalid => {
+                            invalid.get_or_insert("Invalid Unicode escape sequence");
+                        },
+                    }
+                },
+                _ => {
+                    cooked.push(self.char());
+                    self.skip_char()
+                },
+            }
         }
 
         self.down();
         self.skip(1);
 
-        Ok(unit!(String: self.raw()))
+        if let Some(message) = invalid {
+            return err!(InvalidEscape, "{message}")
+        }
+
+        Ok(unit!(String: Str::new(self.raw(), cooked, legacy)))
     }
 }
@@ -0,0 +1,83 @@
+use crate::scanner::{
+    diagnostic::LexResult,
+    helpers::escape::{Cooked, Escape},
+    lit::{Lit, Template},
+    unit::Unit,
+    Scanner,
+};
+
+impl<'s> Scanner<'s> {
+    /// [Template Literal Lexical Components](https://tc39.es/ecma262/#sec-template-literal-lexical-components)
+    ///
+    /// Scan TemplateCharacters after the opening `` ` `` (head) or the `}` closing a substitution
+    /// - `` `...` `` NoSubstitutionTemplate
+    /// - `` `...${ `` TemplateHead
+    /// - `}...${` TemplateMiddle
+    /// - `` }...` `` TemplateTail
+    pub fn scan_template(&mut self, head: bool) -> LexResult<Unit<'s>> {
+        self.mark();
+
+        let mut cooked = Cooked::Raw;
+
+        while !self.is_empty() {
+            match self.byte() {
+                b'`' => {
+                    self.down();
+                    self.skip(1);
+
+                    let template = Template::new(self.raw(), cooked);
+
+                    return if head {
+                        Ok(unit!(NoSubstitutionTemplate: template))
+                    } else {
+                        Ok(unit!(TemplateTail: template))
+                    }
+                },
+                b'$' if self.peek(1) == b'{' => {
+                    self.down();
+                    self.skip(2);
+
+                    // open substitution
+                    self.braces.push(0);
+
+                    let template = Template::new(self.raw(), cooked);
+
+                    return if head {
+                        Ok(unit!(TemplateHead: template))
+                    } else {
+                        Ok(unit!(TemplateMiddle: template))
+                    }
+                },
+                b'\\' => {
+                    self.down();
+                    cooked.own(self.raw());
+                    self.skip(1);
+
+                    if self.is_empty() {
+                        break
+                    }
+
+                    match self.scan_escape() {
+                        Escape::Char(ch) => cooked.push(ch),
+                        Escape::LineContinuation => {},
+                        Escape::Legacy(_) | Escape::Invalid => cooked = Cooked::Invalid,
+                    }
+                },
+                // CR and CRLF are normalized to LF
+                b'\r' => {
+                    self.down();
+                    cooked.own(self.raw());
+                    cooked.push('\n');
+                    self.skip(1);
+                    self.eat(b'\n');
+                },
+                _ => {
+                    cooked.push(self.char());
+                    self.skip_char()
+                },
+            }
+        }
+
+        err!(UnterminatedTemplate, "Unterminated template literal")
+    }
+}
@@ -1,11 +1,60 @@
-use crate::scanner::{helpers::is::Unicode, Scanner};
+use crate::scanner::{
+    helpers::{is::Unicode, simd::eq},
+    trivia::Trivia,
+    Scanner,
+};
 
 impl<'s> Scanner<'s> {
     pub fn skip_space(&mut self) {
+        if self.is_empty() {
+            return
+        }
+
+        // fast path of indentation
+        self.skip_chunks(|chunk| !(eq(chunk, b' ') | eq(chunk, b'\t')));
+
         if let Some(handler) = WHITESPACE_LOOKUP_TABLE[self.byte() as usize] {
             handler(self)
         }
     }
+
+    /// Scan a whitespace run or a single line terminator sequence, skip nothing if neither
+    pub fn scan_trivia(&mut self) -> Option<Trivia<'s>> {
+        self.mark();
+
+        while !self.is_empty() {
+            match self.byte() {
+                b'\t' | 0x0B | 0x0C | b' ' => self.skip(1),
+                0xC2 | 0xE1..=0xE3 | 0xEF if self.char().is_space() => self.skip_char(),
+                _ => break,
+            }
+        }
+
+        if self.ptr != self.lo {
+            self.down();
+
+            return Some(Trivia::Whitespace(self.raw()))
+        }
+
+        if self.is_empty() {
+            return None
+        }
+
+        match self.byte() {
+            b'\r' => {
+                self.skip(1);
+                self.eat(b'\n');
+            },
+            b'\n' => self.skip(1),
+            0xE2 if self.char().is_line_terminator() => self.skip_char(),
+            _ => return None,
+        }
+
+        self.new_line = true;
+        self.down();
+
+        Some(Trivia::NewLine(self.raw()))
+    }
 }
 
 type Handler = fn(&mut Scanner);
@@ -36,7 +85,7 @@ const WHITESPACE_LOOKUP_TABLE: &[Option<Handler>; 256] = &[
 const ___: Option<Handler> = None;
 
 /// End of file
-const EOF: Option<Handler> = Some(|sn: &mut Scanner| sn.skip(1));
+const EOF: Option<Handler> = None;
 
 /// [Space](https://tc39.es/ecma262/#sec-white-space)
 /// - `U+0009`
@@ -52,16 +101,24 @@ const SPC: Option<Handler> = Some(|sn: &mut Scanner| {
 /// - `U+000A`
 /// - `U+000D`
 const NLN: Option<Handler> = Some(|sn: &mut Scanner| {
+    sn.new_line = true;
     sn.skip(1);
     sn.skip_space()
 });
 
 /// Unicode Whitespace
+/// - `U+2028` `U+2029` Line terminator
 const UWS: Option<Handler> = Some(|sn: &mut Scanner| {
-    if sn.char().is_space() || sn.char().is_line_terminator() {
-        sn.skip_char();
-        sn.skip_space()
+    let ch = sn.char();
+
+    if ch.is_line_terminator() {
+        sn.new_line = true;
+    } else if !ch.is_space() {
+        return
     }
+
+    sn.skip_char();
+    sn.skip_space()
 });
 
 // /// `U+00A0` : `[0xC2, 0xA0]`
@@ -1,17 +1,85 @@
-use crate::scanner::helpers::is::Unicode;
+use std::borrow::Cow;
+
+use crate::scanner::{
+    contextual::Contextual,
+    helpers::is::Unicode,
+    keyword::{KeywordExt, ReservedContext},
+};
 
 /// [ECMA IdentifierName][1]
 ///
 /// [1]:https://tc39.es/ecma262/#sec-names-and-keywords
 #[derive(Debug)]
 pub struct Ident<'s> {
+    /// Source slice
     pub raw: &'s str,
+    /// [StringValue][1] with UnicodeEscapeSequence decoded, borrow the raw slice if no escape
+    ///
+    /// [1]:https://tc39.es/ecma262/#sec-identifier-names-static-semantics-stringvalue
+    pub name: Cow<'s, str>,
+    /// Classified while scanning, None if escaped, e.g. `\u0061sync`
+    pub contextual: Option<Contextual>,
 }
 
 impl<'s> Ident<'s> {
     pub fn new(s: &'s str) -> Self {
-        Self { raw: s }
+        let name = if s.as_bytes().contains(&b'\\') {
+            Cow::Owned(unescape(s))
+        } else {
+            Cow::Borrowed(s)
+        };
+
+        Self {
+            raw: s,
+            name,
+            contextual: None,
+        }
+    }
+
+    /// Unescaped contextual keyword
+    pub fn new_contextual(s: &'s str, contextual: Contextual) -> Self {
+        Self {
+            raw: s,
+            name: Cow::Borrowed(s),
+            contextual: Some(contextual),
+        }
+    }
+
+    /// Contains UnicodeEscapeSequence
+    pub fn is_escaped(&self) -> bool {
+        matches!(self.name, Cow::Owned(_))
+    }
+
+    /// Escaped reserved word in the context, e.g. `v\u0061r`, can not be used as keyword nor
+    /// identifier, while `l\u0065t` is a legal identifier in sloppy mode
+    pub fn is_escaped_keyword(&self, context: ReservedContext) -> bool {
+        self.is_escaped() && self.name.is_reserved_word(context)
+    }
+}
+
+/// Decode the `\uXXXX` and `\u{X...}` escapes, keep the escape as is if not a code point
+fn unescape(raw: &str) -> String {
+    let mut name = String::with_capacity(raw.len());
+    let mut rest = raw;
+
+    while let Some((head, tail)) = rest.split_once("\\u") {
+        name.push_str(head);
+
+        let (hex, tail) = match tail.strip_prefix('{') {
+            Some(tail) => tail.split_once('}').unwrap_or((tail, "")),
+            None => tail.split_at_checked(4).unwrap_or((tail, "")),
+        };
+
+        match u32::from_str_radix(hex, 16).ok().and_then(char::from_u32) {
+            Some(ch) => name.push(ch),
+            None => name.push_str(&rest[head.len()..rest.len() - tail.len()]),
+        }
+
+        rest = tail;
     }
+
+    name.push_str(rest);
+    name
 }
 
 pub trait Identifier {
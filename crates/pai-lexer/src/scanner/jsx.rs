@@ -0,0 +1,189 @@
+use std::borrow::Cow;
+
+use crate::scanner::{
+    entry::{self, Entry},
+    helpers::escape::Cooked,
+    punctuator::Punctuator,
+    unit::Unit,
+    Scanner,
+};
+
+/// JSX lexical context, switched by the parser
+///
+/// [JSX Specification](https://facebook.github.io/jsx/)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsxMode {
+    /// Inside `<` `>` of JSXElement, e.g. `<a:b c-d="e">`
+    Tag,
+    /// JSXChildren, e.g. `>text{`
+    Text,
+}
+
+/// JSX unit
+#[derive(Debug)]
+pub enum Jsx<'s> {
+    /// JSXIdentifier, may contain `-`, e.g. `data-id`
+    Ident(&'s str),
+    /// JSXNamespacedName, e.g. `svg:rect`
+    NamespacedName {
+        namespace: &'s str,
+        name: &'s str,
+    },
+    /// JSXString without the quotes
+    String(JsxStr<'s>),
+    Text(JsxStr<'s>),
+}
+
+/// JSXString or JSXText characters
+#[derive(Debug)]
+pub struct JsxStr<'s> {
+    /// Source slice
+    pub raw: &'s str,
+    /// HTML character references decoded, borrow the raw slice if no reference
+    pub value: Cow<'s, str>,
+}
+
+impl<'s> JsxStr<'s> {
+    pub fn new(raw: &'s str, cooked: Cooked) -> Self {
+        let Some(value) = cooked.finish(raw) else {
+            unreachable!("JSX string never contains escape sequence")
+        };
+
+        Self { raw, value }
+    }
+}
+
+pub fn lookup(mode: JsxMode, index: u8) -> &'static Entry {
+    match mode {
+        JsxMode::Tag => &TAG_LOOKUP_TABLE[index as usize],
+        JsxMode::Text => &TEXT_LOOKUP_TABLE[index as usize],
+    }
+}
+
+/// [JsxMode::Tag] entry lookup table
+const TAG_LOOKUP_TABLE: &[Entry; 256] = &[
+    // 0  1    2    3    4    5    6    7    8    9    A    B    C    D    E    F
+    JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, // 0
+    JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, // 1
+    JS_, JS_, JDQ, JS_, JID, JS_, JS_, JSQ, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JSL, // 2
+    JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JGT, JS_, // 3
+    JS_, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, // 4
+    JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JS_, JS_, JS_, JS_, JID, // 5
+    JS_, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, // 6
+    JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JID, JS_, JS_, JS_, JS_, JS_, // 7
+    JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, // 8
+    JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, // 9
+    JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, // A
+    JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, // B
+    JS_, JS_, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, // C
+    JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, // D
+    JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, JUI, // E
+    JUI, JUI, JUI, JUI, JUI, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, JS_, // F
+];
+
+/// [JsxMode::Text] entry lookup table
+const TEXT_LOOKUP_TABLE: &[Entry; 256] = &[
+    // 0  1    2    3    4    5    6    7    8    9    A    B    C    D    E    F
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // 0
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // 1
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // 2
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JLT, JTX, JER, JTX, // 3
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // 4
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // 5
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // 6
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JS_, JTX, JER, JTX, JTX, // 7
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // 8
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // 9
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // A
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // B
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // C
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // D
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // E
+    JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, JTX, // F
+];
+
+/// Same as the ECMAScript entry
+const JS_: Entry = |sn: &mut Scanner| entry::lookup(sn.byte())(sn);
+
+/// JSXIdentifier or JSXNamespacedName
+const JID: Entry = |sn: &mut Scanner| {
+    let ident = sn.scan_jsx_ident();
+
+    if sn.byte() == b':' {
+        let ptr = sn.ptr;
+
+        sn.skip(1);
+
+        if sn.is_jsx_ident_start() {
+            let name = sn.scan_jsx_ident();
+
+            return Ok(Unit::Jsx(Jsx::NamespacedName {
+                namespace: ident,
+                name,
+            }))
+        }
+
+        sn.ptr = ptr
+    }
+
+    Ok(Unit::Jsx(Jsx::Ident(ident)))
+};
+
+/// Unicode start JSXIdentifier
+const JUI: Entry = |sn: &mut Scanner| {
+    if sn.is_jsx_ident_start() {
+        JID(sn)
+    } else {
+        JS_(sn)
+    }
+};
+
+/// Double quote JSXString
+const JDQ: Entry = |sn: &mut Scanner| sn.scan_jsx_string(b'"');
+
+/// Single quote JSXString
+const JSQ: Entry = |sn: &mut Scanner| sn.scan_jsx_string(b'\'');
+
+/// Slash
+/// - `/` never starts regular expression in tag, e.g. `</a>` `<a />`
+/// - `//` `/*` comment
+const JSL: Entry = |sn: &mut Scanner| {
+    if matches!(sn.peek(1), b'/' | b'*') {
+        JS_(sn)
+    } else {
+        sn.skip(1);
+
+        Ok(unit!("/"))
+    }
+};
+
+/// Greater than
+/// - `>` never combines, e.g. `<a>>`
+const JGT: Entry = |sn: &mut Scanner| {
+    sn.skip(1);
+
+    Ok(unit!(">"))
+};
+
+/// JSXText
+const JTX: Entry = |sn: &mut Scanner| Ok(sn.scan_jsx_text());
+
+/// Less than
+/// - `<` opening tag in JSXChildren
+const JLT: Entry = |sn: &mut Scanner| {
+    sn.skip(1);
+
+    Ok(unit!("<"))
+};
+
+/// `>` `}` are not allowed in JSXText
+const JER: Entry = |sn: &mut Scanner| {
+    let byte = sn.byte();
+    sn.skip(1);
+
+    err!(
+        InvalidJsxText,
+        "Unexpected '{}' in JSXText, use HTML entity or JSX expression",
+        byte as char
+    )
+};
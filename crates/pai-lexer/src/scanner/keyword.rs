@@ -1,3 +1,8 @@
+// Reserved in strict mode code, also contextual keywords
+use crate::scanner::contextual::{
+    IMPLEMENTS, INTERFACE, PACKAGE, PRIVATE, PROTECTED, PUBLIC, STATIC,
+};
+
 // [ECMA Keyword][1]
 //
 // [1]:https://tc39.es/ecma262/#sec-keywords-and-reserved-words
@@ -87,14 +92,56 @@ pub const WHILE: &str = "while";
 pub const WITH: &str = "with";
 pub const YIELD: &str = "yield";
 
+// Not binding in strict mode code
+pub const ARGUMENTS: &str = "arguments";
+pub const EVAL: &str = "eval";
+
+/// Context of the [reserved words][1] in addition to [ReservedWord][2]
+///
+/// [1]:https://tc39.es/ecma262/#sec-keywords-and-reserved-words
+/// [2]:https://tc39.es/ecma262/#prod-ReservedWord
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ReservedContext {
+    /// Strict mode code, implied by `module`
+    pub strict: bool,
+    /// Module goal
+    pub module: bool,
+    /// Inside generator function body or parameters
+    pub in_generator: bool,
+    /// Inside async function body or parameters
+    pub in_async: bool,
+}
+
+impl ReservedContext {
+    pub fn is_strict(&self) -> bool {
+        self.strict || self.module
+    }
+}
+
 pub trait KeywordExt {
     fn is_keyword(&self) -> bool;
+
+    /// Reserved in the context, can not be used as [IdentifierReference][1] nor binding
+    /// - `await` in module or async
+    /// - `yield` in strict mode or generator
+    /// - `let` `static` `implements` `interface` `package` `private` `protected` `public` in strict mode
+    ///
+    /// [1]:https://tc39.es/ecma262/#sec-identifiers-static-semantics-early-errors
+    fn is_reserved_word(&self, context: ReservedContext) -> bool;
+
+    /// Legal [BindingIdentifier][1] in the context, `arguments` and `eval` are not in strict mode
+    ///
+    /// [1]:https://tc39.es/ecma262/#prod-BindingIdentifier
+    fn is_binding(&self, context: ReservedContext) -> bool;
 }
 
 impl KeywordExt for str {
     fn is_keyword(&self) -> bool {
-        // SAFETY: first byte of the &str
-        match unsafe { *self.as_ptr() } {
+        let Some(first) = self.as_bytes().first() else {
+            return false
+        };
+
+        match first {
             b'a' => matches!(self, AWAIT),
             b'b' => matches!(self, BREAK),
             b'c' => {
@@ -163,4 +210,23 @@ impl KeywordExt for str {
             _ => false,
         }
     }
+
+    fn is_reserved_word(&self, context: ReservedContext) -> bool {
+        match self {
+            AWAIT => context.module || context.in_async,
+            YIELD => context.is_strict() || context.in_generator,
+            LET | STATIC | IMPLEMENTS | INTERFACE | PACKAGE | PRIVATE | PROTECTED | PUBLIC => {
+                context.is_strict()
+            },
+            _ => self.is_keyword(),
+        }
+    }
+
+    fn is_binding(&self, context: ReservedContext) -> bool {
+        if context.is_strict() && matches!(self, ARGUMENTS | EVAL) {
+            return false
+        }
+
+        !self.is_reserved_word(context)
+    }
 }
@@ -1,5 +1,93 @@
+use std::borrow::Cow;
+
+use crate::scanner::{bigint::BigInt, helpers::escape::Cooked, numeric};
+
 #[derive(Debug)]
 pub enum Lit<'s> {
     Number(&'s str),
-    String(&'s str),
+    /// - `123n`
+    BigInt(&'s str),
+    String(Str<'s>),
+    /// - `` `...` ``
+    NoSubstitutionTemplate(Template<'s>),
+    /// - `` `...${ ``
+    TemplateHead(Template<'s>),
+    /// - `}...${`
+    TemplateMiddle(Template<'s>),
+    /// - `` }...` ``
+    TemplateTail(Template<'s>),
+    /// - `/pattern/flags`
+    RegExp {
+        pattern: &'s str,
+        flags: &'s str,
+    },
+}
+
+impl<'s> Lit<'s> {
+    /// [NumericValue](https://tc39.es/ecma262/#sec-numericvalue) of the Number literal
+    pub fn number_value(&self) -> Option<f64> {
+        match self {
+            Self::Number(raw) => Some(numeric::number_value(raw)),
+            _ => None,
+        }
+    }
+
+    /// [BigIntValue](https://tc39.es/ecma262/#sec-static-semantics-bigintvalue) of the BigInt literal
+    pub fn bigint_value(&self) -> Option<BigInt> {
+        match self {
+            Self::BigInt(raw) => BigInt::from_literal(raw),
+            _ => None,
+        }
+    }
+}
+
+/// [String Literal][1] characters, without the quotes
+///
+/// [1]:https://tc39.es/ecma262/#sec-static-semantics-sv
+#[derive(Debug)]
+pub struct Str<'s> {
+    /// Source slice
+    pub raw: &'s str,
+    /// [SV][1], borrow the raw slice if no escape
+    ///
+    /// [1]:https://tc39.es/ecma262/#sec-static-semantics-sv
+    pub cooked: Cow<'s, str>,
+    /// Contains LegacyOctalEscapeSequence or NonOctalDecimalEscapeSequence, SyntaxError in strict mode
+    pub legacy: bool,
+}
+
+impl<'s> Str<'s> {
+    pub fn new(raw: &'s str, cooked: Cooked, legacy: bool) -> Self {
+        let Some(cooked) = cooked.finish(raw) else {
+            unreachable!("String literal never contains NotEscapeSequence")
+        };
+
+        Self {
+            raw,
+            cooked,
+            legacy,
+        }
+    }
+}
+
+/// [Template Literal][1] characters, without the `` ` ``, `${` and `}` delimiters
+///
+/// [1]:https://tc39.es/ecma262/#sec-static-semantics-tv
+#[derive(Debug)]
+pub struct Template<'s> {
+    /// Source slice
+    pub raw: &'s str,
+    /// [TV][1], None if contains NotEscapeSequence (only legal in tagged template)
+    ///
+    /// [1]:https://tc39.es/ecma262/#sec-static-semantics-tv
+    pub cooked: Option<Cow<'s, str>>,
+}
+
+impl<'s> Template<'s> {
+    pub fn new(raw: &'s str, cooked: Cooked) -> Self {
+        Self {
+            raw,
+            cooked: cooked.finish(raw),
+        }
+    }
 }
@@ -1,17 +1,34 @@
 use std::{marker::PhantomData, slice};
 
-use pai_error::PResult;
+use pai_file::span::{Pos, Span};
 
-use crate::scanner::unit::Unit;
+use crate::scanner::{
+    comment::Comment,
+    diagnostic::{Diagnostic, LexResult},
+    goal::{Goal, SourceType},
+    jsx::JsxMode,
+    token::Token,
+    unit::Unit,
+};
 
+pub mod bigint;
+pub mod checkpoint;
 pub mod comment;
+pub mod contextual;
+pub mod diagnostic;
 #[allow(clippy::collapsible_else_if)]
 pub mod entry;
+pub mod goal;
 pub mod helpers;
 pub mod ident;
+pub mod jsx;
 pub mod keyword;
 pub mod lit;
+pub mod numeric;
 pub mod punctuator;
+pub mod rescan;
+pub mod token;
+pub mod trivia;
 pub mod unit;
 
 /// High performance u8 slice scanner, Inspired by [slice::Iter]
@@ -19,15 +36,40 @@ pub mod unit;
 /// # Safety
 #[derive(Debug)]
 pub struct Scanner<'s> {
+    start: *const u8,
     ptr: *const u8,
     end: *const u8,
 
     lo: *const u8,
     hi: *const u8,
 
+    /// Brace depth stack of the open template substitutions
+    braces: Vec<u32>,
+
+    /// Goal symbol of the next unit
+    goal: Goal,
+
+    /// Whether a line terminator precedes the next unit
+    new_line: bool,
+
+    /// Lexical errors reported so far
+    diagnostics: Vec<Diagnostic>,
+
+    /// Yield whitespace and line terminators as [Unit::Trivia]
+    trivia: bool,
+
+    source_type: SourceType,
+
+    /// Scan with the JSX entries if set
+    jsx_mode: Option<JsxMode>,
+
     _marker: PhantomData<&'s u8>,
 }
 
+// SAFETY: Scanner reads the borrowed `&'s str` only, like `slice::Iter<'s, u8>`
+unsafe impl Send for Scanner<'_> {}
+unsafe impl Sync for Scanner<'_> {}
+
 impl<'s> Scanner<'s> {
     pub fn new(s: &'s str) -> Self {
         // SAFETY: &str guaranteed ptr and end is valid
@@ -36,43 +78,51 @@ impl<'s> Scanner<'s> {
             let end = ptr.add(s.len());
 
             Self {
+                start: ptr,
                 ptr,
                 end,
                 lo: ptr,
                 hi: ptr,
+                braces: Vec::new(),
+                goal: Goal::default(),
+                new_line: false,
+                diagnostics: Vec::new(),
+                trivia: false,
+                source_type: SourceType::default(),
+                jsx_mode: None,
                 _marker: PhantomData,
             }
         }
     }
 
+    /// Current byte, `0` at the end of the source
+    ///
+    /// `0` is the EOF entry of every lookup table, so that the scanner never reads out of the source
     pub fn byte(&self) -> u8 {
-        unsafe { *self.ptr }
+        if self.ptr < self.end {
+            unsafe { *self.ptr }
+        } else {
+            0
+        }
     }
 
+    /// Byte at `count` offset, `0` out of the source
     pub fn peek(&self, count: isize) -> u8 {
-        // !FIX
-        // remove this
-        #[cfg(feature = "safe-scanner-peek")]
-        assert!(count <= self.len() as isize);
+        let ptr = self.ptr.wrapping_offset(count);
 
-        unsafe { *self.ptr.offset(count) }
+        if self.start <= ptr && ptr < self.end {
+            unsafe { *ptr }
+        } else {
+            0
+        }
     }
 
+    /// Skip at most to the end of the source
     pub fn skip(&mut self, count: usize) {
-        #[cfg(feature = "safe-scanner-skip")]
-        assert!(count <= self.len());
-
-        unsafe { self.ptr = self.ptr.add(count) }
+        unsafe { self.ptr = self.ptr.add(count.min(self.len())) }
     }
 
     pub fn eat(&mut self, byte: u8) -> bool {
-        // !FIX
-        // remove this
-        #[cfg(feature = "safe-scanner-eat")]
-        if self.is_empty() {
-            return false
-        }
-
         if self.byte() == byte {
             self.skip(1);
             true
@@ -82,12 +132,17 @@ impl<'s> Scanner<'s> {
     }
 
     pub fn len(&self) -> usize {
-        unsafe { self.end.sub_ptr(self.ptr) }
+        unsafe { self.end.offset_from_unsigned(self.ptr) }
     }
 
     pub fn is_empty(&self) -> bool {
         self.ptr == self.end
     }
+
+    /// Byte position relative to the source start
+    pub fn pos(&self) -> Pos {
+        unsafe { self.ptr.offset_from(self.start) as Pos }
+    }
 }
 
 impl<'s> Scanner<'s> {
@@ -105,13 +160,132 @@ impl<'s> Scanner<'s> {
 }
 
 impl<'s> Scanner<'s> {
-    pub fn next_unit(&mut self) -> Option<PResult<Unit<'s>>> {
-        self.skip_space();
+    pub fn goal(&self) -> Goal {
+        self.goal
+    }
+
+    /// Override the goal of the next unit, which is inferred from the previous unit by default
+    pub fn set_goal(&mut self, goal: Goal) {
+        self.goal = goal
+    }
+
+    /// Brace depth stack of the open template substitutions, innermost last
+    pub fn braces(&self) -> &[u32] {
+        &self.braces
+    }
+
+    pub fn source_type(&self) -> SourceType {
+        self.source_type
+    }
+
+    pub fn set_source_type(&mut self, source_type: SourceType) {
+        self.source_type = source_type
+    }
+
+    pub fn jsx_mode(&self) -> Option<JsxMode> {
+        self.jsx_mode
+    }
+
+    /// Switch to JSX tag or text context, or back to ECMAScript if None
+    pub fn set_jsx_mode(&mut self, jsx_mode: Option<JsxMode>) {
+        self.jsx_mode = jsx_mode
+    }
+
+    /// Lossless mode, the concatenated text of all units reproduces the source
+    pub fn set_trivia(&mut self, trivia: bool) {
+        self.trivia = trivia
+    }
+}
+
+impl<'s> Scanner<'s> {
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    pub fn into_diagnostics(self) -> Vec<Diagnostic> {
+        self.diagnostics
+    }
+
+    /// Next token, lexical error is reported as [Unit::Error] and scanning resumes after it
+    pub fn next_token(&mut self) -> Option<Token<'s>> {
+        if self.ptr == self.start {
+            if let Some(hashbang) = self.scan_hashbang() {
+                let span = Span::new(0, self.pos());
+
+                return Some(Token::new(unit!(Hashbang: hashbang), span, false))
+            }
+        }
+
+        // whitespace is part of JSXText
+        let text = self.jsx_mode == Some(JsxMode::Text);
+
+        if !self.trivia && !text {
+            self.skip_space();
+        }
 
         if self.is_empty() {
-            None
-        } else {
-            Some(entry::lookup(self.byte())(self))
+            return None
+        }
+
+        let lo = self.pos();
+        let new_line = self.new_line;
+
+        if self.trivia && !text {
+            if let Some(trivia) = self.scan_trivia() {
+                return Some(Token::new(
+                    Unit::Trivia(trivia),
+                    Span::new(lo, self.pos()),
+                    new_line,
+                ))
+            }
+        }
+
+        self.mark();
+
+        let entry = match self.jsx_mode {
+            Some(mode) => jsx::lookup(mode, self.byte()),
+            None => entry::lookup(self.byte()),
+        };
+
+        let result = entry(self);
+
+        Some(self.finish(lo, new_line, result))
+    }
+
+    /// Token of the unit scanned from `lo`, update the scanner state for the next unit
+    fn finish(&mut self, lo: Pos, new_line: bool, result: LexResult<Unit<'s>>) -> Token<'s> {
+        let unit = match result {
+            Ok(unit) => unit,
+            Err(diagnostic) => self.recover(lo, diagnostic),
+        };
+
+        // line terminators in comments still precede the next unit
+        if !matches!(unit, Unit::Comment(_)) {
+            self.new_line = false
+        }
+
+        if let Some(goal) = Goal::next(&unit) {
+            self.goal = goal
         }
+
+        Token::new(unit, Span::new(lo, self.pos()), new_line)
+    }
+
+    /// Report the diagnostic, the error unit covers the text from `lo` to the current position
+    fn recover(&mut self, lo: Pos, mut diagnostic: Diagnostic) -> Unit<'s> {
+        // SAFETY: lo is the start position of the current unit
+        self.lo = unsafe { self.start.add(lo as usize) };
+
+        // always make progress
+        if self.ptr == self.lo {
+            self.skip_char()
+        }
+
+        self.down();
+
+        diagnostic.span = Span::new(lo, self.pos());
+        self.diagnostics.push(diagnostic);
+
+        Unit::Error(self.raw())
     }
 }
@@ -0,0 +1,85 @@
+use std::borrow::Cow;
+
+/// [NumericValue][1] of the raw NumericLiteral, correctly rounded to the nearest f64
+/// - `1_000.5e-3` DecimalLiteral
+/// - `0x1F` `0o17` `0b11` NonDecimalIntegerLiteral
+/// - `0777` LegacyOctalIntegerLiteral
+/// - `089` NonOctalDecimalIntegerLiteral
+///
+/// `raw` should be a Number unit of the scanner, which rejects the malformed literals, e.g. `0x` `0_1`
+///
+/// [1]:https://tc39.es/ecma262/#sec-numericvalue
+pub fn number_value(raw: &str) -> f64 {
+    let bytes = raw.as_bytes();
+
+    if bytes.len() > 1 && bytes[0] == b'0' {
+        match bytes[1] {
+            b'x' | b'X' => return radix_value(&raw[2..], 4),
+            b'o' | b'O' => return radix_value(&raw[2..], 3),
+            b'b' | b'B' => return radix_value(&raw[2..], 1),
+            b'0'..=b'7' if bytes.iter().all(|byte| matches!(byte, b'0'..=b'7')) => {
+                return radix_value(&raw[1..], 3)
+            },
+            _ => {},
+        }
+    }
+
+    decimal_value(raw)
+}
+
+/// DecimalLiteral, [f64::from_str] is correctly rounded
+fn decimal_value(raw: &str) -> f64 {
+    let digits = if raw.contains('_') {
+        Cow::Owned(raw.replace('_', ""))
+    } else {
+        Cow::Borrowed(raw)
+    };
+
+    digits.parse().unwrap_or(f64::NAN)
+}
+
+/// Power of 2 radix integer, round half to even if more than 53 significant bits
+fn radix_value(digits: &str, bits_per_digit: u32) -> f64 {
+    let mut mantissa = 0u64;
+    // count of the dropped low bits
+    let mut exponent = 0;
+    // any dropped bit is set
+    let mut sticky = false;
+
+    for digit in digits.bytes().filter(|&byte| byte != b'_') {
+        let digit = (digit as char).to_digit(16).unwrap_or_default() as u64;
+
+        if mantissa >> (u64::BITS - bits_per_digit) == 0 {
+            mantissa = mantissa << bits_per_digit | digit;
+        } else {
+            exponent += bits_per_digit as i32;
+            sticky |= digit != 0;
+        }
+    }
+
+    let width = u64::BITS - mantissa.leading_zeros();
+
+    if width <= f64::MANTISSA_DIGITS {
+        return scale(mantissa as f64, exponent)
+    }
+
+    let shift = width - f64::MANTISSA_DIGITS;
+    let mut kept = mantissa >> shift;
+    let rest = mantissa & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+
+    if rest > half || (rest == half && (sticky || kept & 1 == 1)) {
+        kept += 1;
+    }
+
+    scale(kept as f64, exponent + shift as i32)
+}
+
+/// `value * 2^exponent`, exact until overflow to infinity
+fn scale(value: f64, exponent: i32) -> f64 {
+    if exponent == 0 {
+        value
+    } else {
+        value * 2f64.powi(exponent)
+    }
+}
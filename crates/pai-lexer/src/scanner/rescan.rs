@@ -0,0 +1,82 @@
+//! Re-lex the last token when the parser changes its mind about the context
+//!
+//! Inspired by the `reScan*` functions of the TypeScript scanner,
+//! the token passed in must be the last token returned by [Scanner::next_token] or a rescan
+
+use crate::scanner::{
+    entry::{self, Entry},
+    goal::Goal,
+    lit::Lit,
+    punctuator::Punctuator,
+    token::Token,
+    unit::Unit,
+    Scanner,
+};
+
+/// Single `>`
+const GT_: Entry = |sn: &mut Scanner| {
+    sn.skip(1);
+
+    Ok(unit!(">"))
+};
+
+/// Single `<`
+const LT_: Entry = |sn: &mut Scanner| {
+    sn.skip(1);
+
+    Ok(unit!("<"))
+};
+
+/// `}` closing a template substitution
+const TPL: Entry = |sn: &mut Scanner| {
+    sn.skip(1);
+
+    sn.scan_template(false)
+};
+
+impl<'s> Scanner<'s> {
+    /// Split `>>` `>>>` `>=` `>>=` `>>>=` into the leading `>`, the rest is scanned by the next token
+    /// - `Array<Array<number>>` closing type arguments
+    pub fn rescan_gt(&mut self, token: &Token<'s>) -> Token<'s> {
+        self.rescan(token, |byte| (byte == b'>').then_some(GT_))
+    }
+
+    /// Split `<<` `<=` `<<=` into the leading `<`, which opens type arguments or type parameters
+    /// - `f<<T>(x: T) => T>(g)`
+    pub fn rescan_lt(&mut self, token: &Token<'s>) -> Token<'s> {
+        self.rescan(token, |byte| (byte == b'<').then_some(LT_))
+    }
+
+    /// Re-lex `/` or `/=` as regular expression literal, regardless of the [Goal]
+    pub fn rescan_slash(&mut self, token: &Token<'s>) -> Token<'s> {
+        self.goal = Goal::RegExp;
+
+        self.rescan(token, |_| None)
+    }
+
+    /// Re-lex `}` as TemplateMiddle or TemplateTail
+    pub fn rescan_template_tail(&mut self, token: &Token<'s>) -> Token<'s> {
+        // `}` punctuator has counted into the brace depth of the substitution,
+        // TemplateMiddle has opened the next one, close it instead
+        if let Unit::Punctuator(Punctuator::RBrace) | Unit::Lit(Lit::TemplateMiddle(_)) = token.unit
+        {
+            self.braces.pop();
+        }
+
+        self.rescan(token, |byte| (byte == b'}').then_some(TPL))
+    }
+
+    /// Scan again from the token start, with the entry of `select` or the byte entry by default
+    fn rescan(&mut self, token: &Token<'s>, select: fn(u8) -> Option<Entry>) -> Token<'s> {
+        let lo = token.span.lo;
+
+        // SAFETY: span of the token is in the source
+        self.ptr = unsafe { self.start.add(lo as usize) };
+        self.mark();
+
+        let entry = select(self.byte()).unwrap_or(*entry::lookup(self.byte()));
+        let result = entry(self);
+
+        self.finish(lo, token.new_line, result)
+    }
+}
@@ -0,0 +1,29 @@
+use pai_file::span::Span;
+
+use crate::scanner::unit::Unit;
+
+/// [Unit] with its source [Span]
+#[derive(Debug)]
+pub struct Token<'s> {
+    pub unit: Unit<'s>,
+    /// Byte range relative to the source start
+    pub span: Span,
+    /// Whether a [LineTerminator](https://tc39.es/ecma262/#sec-line-terminators) precedes the unit,
+    /// including line terminators in the skipped comments
+    pub new_line: bool,
+}
+
+impl<'s> Token<'s> {
+    pub fn new(unit: Unit<'s>, span: Span, new_line: bool) -> Self {
+        Self {
+            unit,
+            span,
+            new_line,
+        }
+    }
+
+    /// Source text covered by the span
+    pub fn text<'a>(&self, src: &'a str) -> &'a str {
+        &src[self.span.lo as usize..self.span.hi as usize]
+    }
+}
@@ -0,0 +1,9 @@
+/// Trivia unit in [trivia mode][crate::scanner::Scanner::set_trivia], comments are always units
+#[derive(Debug)]
+pub enum Trivia<'s> {
+    /// Run of [WhiteSpace](https://tc39.es/ecma262/#sec-white-space)
+    Whitespace(&'s str),
+    /// Single [LineTerminatorSequence](https://tc39.es/ecma262/#prod-LineTerminatorSequence)
+    /// - LF CR CRLF LS PS
+    NewLine(&'s str),
+}
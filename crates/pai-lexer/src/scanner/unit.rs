@@ -1,5 +1,6 @@
 use crate::scanner::{
-    comment::Comment, ident::Ident, keyword::Keyword, lit::Lit, punctuator::Punctuator,
+    comment::Comment, contextual::Contextual, ident::Ident, jsx::Jsx, keyword::Keyword, lit::Lit,
+    punctuator::Punctuator, trivia::Trivia,
 };
 
 /// Unit enum has the same representation as
@@ -32,10 +33,22 @@ pub enum Unit<'s> {
 
     Ident(Ident<'s>),
 
+    /// `#` IdentifierName, the ident excludes `#`
+    PrivateName(Ident<'s>),
+
     /// literal
     Lit(Lit<'s>),
 
     Comment(Comment<'s>),
+
+    /// Only in [JSX mode][crate::scanner::Scanner::set_jsx_mode]
+    Jsx(Jsx<'s>),
+
+    /// Only in [trivia mode][crate::scanner::Scanner::set_trivia]
+    Trivia(Trivia<'s>),
+
+    /// Invalid text, see [Diagnostic][crate::scanner::diagnostic::Diagnostic]
+    Error(&'s str),
 }
 
 impl<'s> Unit<'s> {
@@ -45,4 +58,36 @@ impl<'s> Unit<'s> {
         // Unit store u8 discriminant as its first field, so we can read the discriminant as unit id.
         unsafe { *<*const Self>::from(self).cast::<u8>() }
     }
+
+    pub fn kind(&self) -> Kind {
+        match self {
+            Self::Keyword(keyword) => Kind::Keyword(*keyword),
+            Self::Punctuator(punctuator) => Kind::Punctuator(*punctuator),
+            Self::Ident(ident) => Kind::Ident(ident.contextual),
+            Self::PrivateName(_) => Kind::PrivateName,
+            Self::Lit(_) => Kind::Lit,
+            Self::Comment(_) => Kind::Comment,
+            Self::Jsx(_) => Kind::Jsx,
+            Self::Trivia(_) => Kind::Trivia,
+            Self::Error(_) => Kind::Error,
+        }
+    }
+}
+
+/// [Unit] without payload, cheap to copy and compare in lookahead
+/// - `async function`
+/// - `let [`
+/// - `type X =`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    Keyword(Keyword),
+    Punctuator(Punctuator),
+    /// Contextual keyword if the name matches
+    Ident(Option<Contextual>),
+    PrivateName,
+    Lit,
+    Comment,
+    Jsx,
+    Trivia,
+    Error,
 }
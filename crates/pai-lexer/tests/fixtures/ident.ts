@@ -0,0 +1,4 @@
+const \u0061bc = a\u{62}c + _$$;
+var = \u{76}ar;
+\u{1F600};
+a\u0020b;
@@ -0,0 +1,5 @@
+const re = /ab+c/gi;
+const cls = /[/\]]+\//u.test(x) ? a / b : c /= 2;
+if (x) {} else
+/^#!/.exec(src);
+return /\d+/dgimsy, typeof /x/;
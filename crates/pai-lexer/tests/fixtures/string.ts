@@ -0,0 +1,6 @@
+const a = 'single "quote"';
+const b = "double \'quote\'";
+const c = "\x41\u0042\u{43}\uD83D\uDE00\b\f\n\r\t\v\0\\";
+const d = 'legacy \101\08\8 octal';
+const e = "line \
+continuation \u2028";
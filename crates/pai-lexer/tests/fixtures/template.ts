@@ -0,0 +1,5 @@
+const a = `no substitution`;
+const b = `head ${a} middle ${ { c: 1 } } tail`;
+const c = `nested ${`inner ${b}`} \x41\u{1F600}😀 line\
+continuation`;
+tag`invalid \unicode and \1`;
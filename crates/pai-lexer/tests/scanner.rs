@@ -1,14 +1,1156 @@
+use std::{num::NonZeroUsize, sync::Arc, thread};
+
 use insta::assert_snapshot;
-use pai_file::SourceFile;
-use pai_lexer::Lexer;
+use pai_file::{span::Span, SourceFile};
+use pai_lexer::{
+    atom::{Atom, Interner},
+    batch::{lex_files, lex_sources, Lexed},
+    incremental::{relex, Restarts, TextEdit, RESTART_INTERVAL},
+    lookahead::LOOKAHEAD,
+    scanner::{
+        bigint::BigInt,
+        checkpoint::Checkpoint,
+        comment::Comment,
+        contextual::Contextual,
+        diagnostic::Code,
+        goal::{Goal, SourceType},
+        jsx::{Jsx, JsxMode},
+        keyword::{Keyword, KeywordExt, ReservedContext},
+        lit::Lit,
+        numeric::number_value,
+        punctuator::Punctuator,
+        token::Token,
+        trivia::Trivia,
+        unit::{Kind, Unit},
+    },
+    Lexer,
+};
+
+/// Fixtures under `tests/fixtures`, without the `.ts` extension
+const FIXTURES: [&str; 5] = ["demo", "template", "regexp", "string", "ident"];
+
+fn read_fixture(name: &str) -> SourceFile {
+    SourceFile::read(format!("tests/fixtures/{name}.ts")).unwrap()
+}
+
+/// Debug output of the tokens of the fixture, one per line
+fn lex_fixture(name: &str) -> String {
+    let source_file = read_fixture(name);
+
+    let lexer = Lexer::new(source_file.source());
+
+    lexer.map(|unit| format!("{unit:?}\n")).collect()
+}
+
+/// Whether the first unit of `src` is an error unit with diagnostic
+fn is_error(src: &str) -> bool {
+    let mut lexer = Lexer::new(src);
+
+    let error = matches!(
+        lexer.next(),
+        Some(Token {
+            unit: Unit::Error(_),
+            ..
+        })
+    );
+
+    error && lexer.diagnostics().len() == 1
+}
 
 #[test]
 fn main() {
-    let source_file = SourceFile::read("tests/fixtures/demo.ts").unwrap();
+    assert_snapshot!(lex_fixture("demo"));
+}
 
-    let lexer = Lexer::new(source_file.source());
+#[test]
+fn template() {
+    assert_snapshot!(lex_fixture("template"));
+}
+
+#[test]
+fn regexp() {
+    assert_snapshot!(lex_fixture("regexp"));
+}
+
+#[test]
+fn regexp_goal() {
+    let mut lexer = Lexer::new("a\n/b/g\n");
+
+    assert!(matches!(
+        lexer.next(),
+        Some(Token {
+            unit: Unit::Ident(_),
+            ..
+        })
+    ));
+    assert_eq!(lexer.goal(), Goal::Div);
+
+    lexer.set_goal(Goal::RegExp);
+
+    let Some(Token {
+        unit: Unit::Lit(Lit::RegExp { pattern, flags }),
+        ..
+    }) = lexer.next()
+    else {
+        panic!("expect RegExp")
+    };
+    assert_eq!((pattern, flags), ("b", "g"));
+
+    for src in ["/a/gg\n", "/a/uv\n", "/a/x\n", "/a\n/\n", "/[/\n"] {
+        assert!(is_error(src), "{src}");
+    }
+}
+
+#[test]
+fn string() {
+    assert_snapshot!(lex_fixture("string"));
+
+    for src in ["'\n", "'a\n'\n", "'\\x4'\n", "'\\u{110000}'\n", "'\\u12'\n"] {
+        assert!(is_error(src), "{src}");
+    }
+}
+
+#[test]
+fn ident() {
+    assert_snapshot!(lex_fixture("ident"));
+
+    let ident = |src| {
+        let Some(Token {
+            unit: Unit::Ident(ident),
+            ..
+        }) = Lexer::new(src).next()
+        else {
+            panic!("expect Ident: {src}")
+        };
+        ident
+    };
+
+    assert_eq!(ident("\\u{61}b\\u0063\n").name, "abc");
+    assert_eq!(ident("a\\u{000062}\n").name, "ab");
+
+    let sloppy = ReservedContext::default();
+    let strict = ReservedContext {
+        strict: true,
+        ..sloppy
+    };
+
+    for (src, sloppy_reserved, strict_reserved) in [
+        ("v\\u0061r\n", true, true),
+        ("l\\u0065t\n", false, true),
+        ("yi\\u0065ld\n", false, true),
+        ("\\u0061wait\n", false, false),
+        ("\\u0061sync\n", false, false),
+    ] {
+        assert_eq!(
+            ident(src).is_escaped_keyword(sloppy),
+            sloppy_reserved,
+            "{src}"
+        );
+        assert_eq!(
+            ident(src).is_escaped_keyword(strict),
+            strict_reserved,
+            "{src}"
+        );
+    }
+
+    // each escape is a code point on its own, surrogates are not combined
+    for src in ["\\uD835\\uDC00\n", "\\u{D800}\n"] {
+        assert!(is_error(src), "{src}");
+    }
+
+    // the error unit covers the invalid escapes and the rest of the identifier
+    for (src, error) in [
+        ("\\uD835\\uDC00 x\n", "\\uD835\\uDC00"),
+        ("a\\u{1F600}b x\n", "a\\u{1F600}b"),
+        ("\\u{zz}a x\n", "\\u{zz}a"),
+        ("#a\\u12 x\n", "#a\\u12"),
+    ] {
+        let mut lexer = Lexer::new(src);
+
+        assert!(
+            matches!(lexer.next().unwrap().unit, Unit::Error(raw) if raw == error),
+            "{src}"
+        );
+        assert!(
+            matches!(lexer.next().unwrap().unit, Unit::Ident(_)),
+            "{src}"
+        );
+        assert_eq!(lexer.diagnostics().len(), 1, "{src}");
+    }
+}
+
+#[test]
+fn number() {
+    let cases = [
+        ("0", 0.0),
+        ("1_000", 1000.0),
+        (".5", 0.5),
+        ("5.", 5.0),
+        ("1.5e3", 1500.0),
+        ("1E-2", 0.01),
+        ("0.1534", 0.1534),
+        ("0x1F", 31.0),
+        ("0XfF_fF", 65535.0),
+        ("0o17", 15.0),
+        ("0b1010_1010", 170.0),
+        ("0777", 511.0),
+        ("089", 89.0),
+        ("08.5", 8.5),
+        ("9007199254740993", 9007199254740992.0),
+        ("0x20000000000001", 9007199254740992.0),
+        ("0x20000000000003", 9007199254740996.0),
+        (
+            "0x20000000000001_000000000000000000000001",
+            9007199254740994.0 * 2f64.powi(96),
+        ),
+        ("1e400", f64::INFINITY),
+    ];
+
+    for (src, value) in cases {
+        let src = format!("{src}\n");
+
+        let Some(Token {
+            unit: Unit::Lit(lit),
+            ..
+        }) = Lexer::new(&src).next()
+        else {
+            panic!("expect Number {src}")
+        };
+
+        assert_eq!(lit.number_value(), Some(value), "{src}");
+    }
+
+    assert_eq!(
+        number_value(&format!("0x1{}", "0".repeat(256))),
+        f64::INFINITY
+    );
+
+    for src in [
+        "0_1\n", "08_1\n", "07e1\n", "0x\n", "0b\n", "0o;\n", "3in\n", "0b12\n", "0x1g\n",
+        "1e3e\n", "1.a\n", "5$\n",
+    ] {
+        assert!(is_error(src), "{src}");
+    }
+
+    // the error unit covers the whole run
+    let mut lexer = Lexer::new("3in x\n");
+    assert!(matches!(lexer.next().unwrap().unit, Unit::Error("3in")));
+
+    // LegacyOctalIntegerLiteral takes no fraction, `07.5` is `07` then `.5`
+    let values: Vec<_> = Lexer::new("07.5\n")
+        .map(|token| {
+            match token.unit {
+                Unit::Lit(lit) => lit.number_value(),
+                _ => None,
+            }
+        })
+        .collect();
+    assert_eq!(values, [Some(7.0), Some(0.5)]);
+}
+
+#[test]
+fn bigint() {
+    let cases = [
+        ("0n", "0"),
+        ("1_000n", "1000"),
+        ("0x1Fn", "31"),
+        ("0o17n", "15"),
+        ("0b1010_1010n", "170"),
+        (
+            "0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFFn",
+            "340282366920938463463374607431768211455",
+        ),
+    ];
+
+    for (src, value) in cases {
+        let src = format!("{src}\n");
+
+        let Some(Token {
+            unit: Unit::Lit(lit),
+            ..
+        }) = Lexer::new(&src).next()
+        else {
+            panic!("expect BigInt {src}")
+        };
+
+        assert_eq!(lit.bigint_value().unwrap().to_string(), value, "{src}");
+    }
+
+    for src in [
+        "0777n\n", "08n\n", "1.5n\n", "1e3n\n", ".5n\n", "5.n\n", "1nn\n", "0xn\n",
+    ] {
+        assert!(is_error(src), "{src}");
+    }
+
+    for src in ["1__0n\n", "1_n\n", "0x_1n\n"] {
+        assert!(is_error(src), "{src}");
+    }
+
+    for raw in ["1__0n", "_1n", "1_n", "0x_1n"] {
+        assert_eq!(BigInt::from_literal(raw), None, "{raw}");
+    }
+
+    let mut lexer = Lexer::new("1.5n x\n");
+    assert!(matches!(lexer.next().unwrap().unit, Unit::Error("1.5n")));
+    assert_eq!(lexer.diagnostics()[0].code, Code::InvalidNumber);
+
+    let a = BigInt::from_literal("123456789012345678901234567890n").unwrap();
+    let b = BigInt::from_literal("0x1_0000_0000n").unwrap();
+
+    assert_eq!(a.to_str_radix(16), "18ee90ff6c373e0ee4e3f0ad2");
+    assert_eq!(
+        (&a * &b).to_string(),
+        "530242871277196831127719683112241725440"
+    );
+    assert_eq!((&b - &a).to_string(), "-123456789012345678896939600594");
+    assert_eq!(
+        (&-&a + &b).to_str_radix(2),
+        format!("-{}", (&a - &b).to_str_radix(2))
+    );
+
+    let (quotient, remainder) = (-&a).div_rem(&b).unwrap();
+    assert_eq!(quotient.to_string(), "-28744523649184424174");
+    assert_eq!(remainder.to_string(), "-1312754386");
+    assert!((-&a).div_rem(&BigInt::ZERO).is_none());
+
+    assert!(-&a < b && b < a && BigInt::from(-1) < BigInt::ZERO);
+    assert_eq!(BigInt::from(i64::MIN).to_string(), i64::MIN.to_string());
+}
+
+#[test]
+fn span() {
+    let src = "const s = `a${b}c`; // end\n";
+
+    let spans: Vec<_> = Lexer::new(src)
+        .map(|token| (token.span.lo, token.span.hi, token.text(src)))
+        .collect();
+
+    assert_eq!(spans, [
+        (0, 5, "const"),
+        (6, 7, "s"),
+        (8, 9, "="),
+        (10, 14, "`a${"),
+        (14, 15, "b"),
+        (15, 18, "}c`"),
+        (18, 19, ";"),
+        (20, 26, "// end"),
+    ]);
+}
+
+#[test]
+fn new_line() {
+    let src = "a\nb /* \n */ c /* */ d // e\nf\u{2028}g\r\nh\n";
+
+    let new_lines: Vec<_> = Lexer::new(src)
+        .map(|token| (token.text(src), token.new_line))
+        .collect();
+
+    assert_eq!(new_lines, [
+        ("a", false),
+        ("b", true),
+        ("/* \n */", false),
+        ("c", true),
+        ("/* */", false),
+        ("d", false),
+        ("// e", false),
+        ("f", true),
+        ("g", true),
+        ("h", true),
+    ]);
+}
+
+#[test]
+fn diagnostic() {
+    let src = "a \u{1} 'b\\xZ' c\n'd\ne 1__0 f /x/gg ..\n";
+
+    let mut lexer = Lexer::new(src);
+
+    let units: Vec<_> = lexer
+        .by_ref()
+        .map(|token| (token.text(src), matches!(token.unit, Unit::Error(_))))
+        .collect();
+
+    assert_eq!(units, [
+        ("a", false),
+        ("\u{1}", true),
+        ("'b\\xZ'", true),
+        ("c", false),
+        ("'d", true),
+        ("e", false),
+        ("1__0", true),
+        ("f", false),
+        ("/", false),
+        ("x", false),
+        ("/", false),
+        ("gg", false),
+        ("..", true),
+    ]);
+
+    let diagnostics: Vec<_> = lexer
+        .diagnostics()
+        .iter()
+        .map(|diagnostic| (diagnostic.code, diagnostic.span.lo, diagnostic.span.hi))
+        .collect();
+
+    assert_eq!(diagnostics, [
+        (Code::InvalidChar, 2, 3),
+        (Code::InvalidEscape, 4, 10),
+        (Code::UnterminatedString, 13, 15),
+        (Code::InvalidNumber, 18, 22),
+        (Code::InvalidPunctuator, 31, 33),
+    ]);
+    assert_eq!(lexer.diagnostics()[0].message, "Invalid char '\\x01'");
+}
+
+#[test]
+fn contextual() {
+    let src = "async of x satisfies keyof \\u0061sync asynchronous readonly\n";
+
+    let contextual: Vec<_> = Lexer::new(src)
+        .map(|token| {
+            match token.unit {
+                Unit::Ident(ident) => ident.contextual,
+                unit => panic!("expect Ident {unit:?}"),
+            }
+        })
+        .collect();
+
+    assert_eq!(contextual, [
+        Some(Contextual::Async),
+        Some(Contextual::Of),
+        None,
+        Some(Contextual::Satisfies),
+        Some(Contextual::Keyof),
+        None,
+        None,
+        Some(Contextual::Readonly),
+    ]);
+    assert_eq!(Contextual::Accessor.raw(), "accessor");
+
+    let src = "interface implements public private protected package pub\n";
+
+    let contextual: Vec<_> = Lexer::new(src)
+        .map(|token| {
+            match token.unit {
+                Unit::Ident(ident) => ident.contextual,
+                unit => panic!("expect Ident {unit:?}"),
+            }
+        })
+        .collect();
+
+    assert_eq!(contextual, [
+        Some(Contextual::Interface),
+        Some(Contextual::Implements),
+        Some(Contextual::Public),
+        Some(Contextual::Private),
+        Some(Contextual::Protected),
+        Some(Contextual::Package),
+        None,
+    ]);
+}
+
+#[test]
+fn reserved_word() {
+    let sloppy = ReservedContext::default();
+    let strict = ReservedContext {
+        strict: true,
+        ..sloppy
+    };
+    let module = ReservedContext {
+        module: true,
+        ..sloppy
+    };
+    let generator = ReservedContext {
+        in_generator: true,
+        ..sloppy
+    };
+    let r#async = ReservedContext {
+        in_async: true,
+        ..sloppy
+    };
+
+    let cases = [
+        // name, sloppy, strict, module, generator, async
+        ("foo", false, false, false, false, false),
+        ("var", true, true, true, true, true),
+        ("enum", true, true, true, true, true),
+        ("let", false, true, true, false, false),
+        ("static", false, true, true, false, false),
+        ("implements", false, true, true, false, false),
+        ("public", false, true, true, false, false),
+        ("yield", false, true, true, true, false),
+        ("await", false, false, true, false, true),
+        ("async", false, false, false, false, false),
+        ("", false, false, false, false, false),
+    ];
+
+    for (name, a, b, c, d, e) in cases {
+        let reserved = [sloppy, strict, module, generator, r#async]
+            .map(|context| name.is_reserved_word(context));
+
+        assert_eq!(reserved, [a, b, c, d, e], "{name}");
+    }
+
+    assert!("eval".is_binding(sloppy) && !"eval".is_binding(strict));
+    assert!(!"arguments".is_binding(module) && "arguments".is_binding(r#async));
+    assert!("let".is_binding(sloppy) && !"let".is_binding(strict));
+    assert!(!String::new().as_str().is_keyword());
+}
+
+#[test]
+fn private_name() {
+    let src = "this.#count #\\u0061b #$_1 in\n";
+
+    let names: Vec<_> = Lexer::new(src)
+        .filter_map(|token| {
+            match &token.unit {
+                Unit::PrivateName(ident) => Some(format!("{} {}", token.text(src), ident.name)),
+                _ => None,
+            }
+        })
+        .collect();
+
+    assert_eq!(names, ["#count count", "#\\u0061b ab", "#$_1 $_1"]);
+
+    for src in ["# a\n", "#\n", "#\u{3000}a\n"] {
+        assert!(is_error(src), "{src}");
+    }
+}
+
+#[test]
+fn hashbang() {
+    let src = "#!/usr/bin/env node\na\n";
+
+    let mut lexer = Lexer::new(src);
+
+    let Some(Token {
+        unit: Unit::Comment(Comment::Hashbang(hashbang)),
+        span,
+        new_line: false,
+    }) = lexer.next()
+    else {
+        panic!("expect Hashbang")
+    };
+    assert_eq!((hashbang, span.lo, span.hi), ("/usr/bin/env node", 0, 19));
+    assert!(matches!(lexer.next(), Some(Token { new_line: true, .. })));
+
+    for src in [" #!a\n", "a #!b\n", "\n#!a\n"] {
+        let mut lexer = Lexer::new(src);
+
+        assert!(
+            lexer.any(|token| matches!(token.unit, Unit::Error("#!"))),
+            "{src}"
+        );
+        assert_eq!(lexer.diagnostics()[0].code, Code::InvalidHashbang);
+    }
+}
+
+#[test]
+fn trivia() {
+    for name in FIXTURES {
+        let source_file = read_fixture(name);
+        let src = source_file.source();
+
+        let mut lexer = Lexer::new(src);
+        lexer.set_trivia(true);
+
+        let content: String = lexer.map(|token| token.text(src)).collect();
+
+        assert_eq!(content, src, "{name}");
+    }
+
+    let src = "a \t\r\nb\u{2028}\u{3000}c\n";
+
+    let mut lexer = Lexer::new(src);
+    lexer.set_trivia(true);
+
+    let units: Vec<_> = lexer
+        .map(|token| {
+            let kind = match token.unit {
+                Unit::Trivia(Trivia::Whitespace(_)) => "space",
+                Unit::Trivia(Trivia::NewLine(_)) => "line",
+                _ => "unit",
+            };
+
+            (token.text(src), kind, token.new_line)
+        })
+        .collect();
+
+    assert_eq!(units, [
+        ("a", "unit", false),
+        (" \t", "space", false),
+        ("\r\n", "line", false),
+        ("b", "unit", true),
+        ("\u{2028}", "line", false),
+        ("\u{3000}", "space", true),
+        ("c", "unit", true),
+        ("\n", "line", false),
+    ]);
+}
+
+#[test]
+fn html_comment() {
+    let src = "--> a\nx <!-- b\nx-->0\n /**/ --> c\n";
+
+    let comments = |source_type| {
+        let mut lexer = Lexer::new(src);
+        lexer.set_source_type(source_type);
+
+        lexer
+            .filter_map(|token| {
+                match token.unit {
+                    Unit::Comment(Comment::Html(comment)) => Some(comment),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(comments(SourceType::Script), [" a", " b", " c"]);
+    assert!(comments(SourceType::Module).is_empty());
+}
+
+#[test]
+fn jsx() {
+    let src =
+        "<svg:rect data-id=\"a\\b &amp;\" class='x'>Hi &lt;&#x41;&#65; &bogus; {name}</svg:rect>\n";
+
+    let mut lexer = Lexer::new(src);
+    let mut units = Vec::new();
+
+    // switch modes as the parser would
+    for mode in [
+        None,
+        Some(JsxMode::Tag),
+        Some(JsxMode::Tag),
+        Some(JsxMode::Tag),
+        Some(JsxMode::Tag),
+        Some(JsxMode::Tag),
+        Some(JsxMode::Tag),
+        Some(JsxMode::Tag),
+        Some(JsxMode::Tag),
+        Some(JsxMode::Text),
+        Some(JsxMode::Text),
+        None,
+        None,
+        Some(JsxMode::Text),
+        Some(JsxMode::Tag),
+        Some(JsxMode::Tag),
+    ] {
+        lexer.set_jsx_mode(mode);
+
+        let token = lexer.next().unwrap();
+
+        let unit = match token.unit {
+            Unit::Jsx(Jsx::Ident(ident)) => format!("Ident {ident}"),
+            Unit::Jsx(Jsx::NamespacedName { namespace, name }) => {
+                format!("Name {namespace} {name}")
+            },
+            Unit::Jsx(Jsx::String(str)) => format!("String {}", str.value),
+            Unit::Jsx(Jsx::Text(text)) => format!("Text {}", text.value),
+            Unit::Punctuator(punctuator) => punctuator.raw().to_owned(),
+            Unit::Ident(ident) => ident.raw.to_owned(),
+            unit => panic!("unexpected {unit:?}"),
+        };
+
+        units.push(unit);
+    }
+
+    assert_eq!(units, [
+        "<",
+        "Name svg rect",
+        "Ident data-id",
+        "=",
+        "String a\\b &",
+        "Ident class",
+        "=",
+        "String x",
+        ">",
+        "Text Hi <AA &bogus; ",
+        "{",
+        "name",
+        "}",
+        "<",
+        "/",
+        "Name svg rect",
+    ]);
+
+    let mut lexer = Lexer::new("a > b\n");
+    lexer.set_jsx_mode(Some(JsxMode::Text));
+
+    assert!(matches!(
+        lexer.next(),
+        Some(Token {
+            unit: Unit::Jsx(Jsx::Text(_)),
+            ..
+        })
+    ));
+    assert!(matches!(
+        lexer.next(),
+        Some(Token {
+            unit: Unit::Error(">"),
+            ..
+        })
+    ));
+    assert_eq!(lexer.diagnostics()[0].code, Code::InvalidJsxText);
+}
+
+#[test]
+fn rescan() {
+    let text = |token: &Token, src: &str| token.text(src).to_owned();
+
+    // split `>>` closing nested type arguments
+    let src = "Array<Array<number>>=x\n";
+    let mut lexer = Lexer::new(src);
+    let mut texts = Vec::new();
+
+    while let Some(mut token) = lexer.next() {
+        if let Unit::Punctuator(punctuator) = &token.unit {
+            if punctuator.raw().starts_with('>') && texts.len() < 7 {
+                token = lexer.rescan_gt(&token);
+            }
+        }
+
+        texts.push(text(&token, src));
+    }
+
+    assert_eq!(texts, [
+        "Array", "<", "Array", "<", "number", ">", ">", "=", "x"
+    ]);
+
+    // `<<` opening type parameters
+    let src = "f<<T>(x: T) => T>(g)\n";
+    let mut lexer = Lexer::new(src);
+
+    lexer.next();
+    let token = lexer.next().unwrap();
+    assert_eq!(text(&token, src), "<<");
+
+    let token = lexer.rescan_lt(&token);
+    assert_eq!(text(&token, src), "<");
+    assert_eq!(text(&lexer.next().unwrap(), src), "<");
+    assert_eq!(text(&lexer.next().unwrap(), src), "T");
+
+    // `/=` as regular expression
+    let src = "x = a\n/=b/g\n";
+    let mut lexer = Lexer::new(src);
+
+    let token = lexer.nth(3).unwrap();
+    assert_eq!(text(&token, src), "/=");
+
+    let token = lexer.rescan_slash(&token);
+    assert!(token.new_line);
+    assert!(matches!(
+        token.unit,
+        Unit::Lit(Lit::RegExp {
+            pattern: "=b",
+            flags: "g"
+        })
+    ));
+    assert!(lexer.next().is_none());
+
+    // `}` closing a substitution
+    let src = "`a${ {}.b }c${d}e`\n";
+    let mut lexer = Lexer::new(src);
+    let mut texts = Vec::new();
+
+    while let Some(mut token) = lexer.next() {
+        if token.span.lo == 10 {
+            token = lexer.rescan_template_tail(&token);
+        }
+
+        texts.push(text(&token, src));
+    }
+
+    assert_eq!(texts, ["`a${", "{", "}", ".", "b", "}c${", "d", "}e`"]);
+}
+
+#[test]
+fn checkpoint() {
+    let src = "(a, b) => `${a}` / b\n\u{7f}\n";
+    let mut lexer = Lexer::new(src);
+
+    let texts = |lexer: &mut Lexer, count: usize| -> Vec<String> {
+        lexer
+            .take(count)
+            .map(|token| token.text(src).to_owned())
+            .collect()
+    };
+
+    assert_eq!(texts(&mut lexer, 1), ["("]);
+
+    // try parenthesized expression, backtrack to arrow function
+    let checkpoint: Checkpoint = lexer.checkpoint();
+    assert_eq!(checkpoint.pos(), 1);
+
+    assert_eq!(texts(&mut lexer, 6), ["a", ",", "b", ")", "=>", "`${"]);
+    lexer.set_goal(Goal::RegExp);
+    lexer.set_jsx_mode(Some(JsxMode::Tag));
+
+    lexer.restore(checkpoint.clone());
+    assert_eq!(lexer.checkpoint(), checkpoint);
+    assert_eq!(lexer.jsx_mode(), None);
+
+    assert_eq!(texts(&mut lexer, 6), ["a", ",", "b", ")", "=>", "`${"]);
+
+    // template depth and goal are restored, `/` after the template is division
+    let checkpoint = lexer.checkpoint();
+    assert_eq!(texts(&mut lexer, 4), ["a", "}`", "/", "b"]);
+    assert_eq!(lexer.diagnostics().len(), 0);
+
+    let token = lexer.next().unwrap();
+    assert!(token.new_line);
+    assert!(matches!(token.unit, Unit::Error("\u{7f}")));
+    assert_eq!(lexer.diagnostics().len(), 1);
+
+    lexer.restore(checkpoint);
+    assert_eq!(lexer.diagnostics().len(), 0);
+    assert_eq!(texts(&mut lexer, 6), ["a", "}`", "/", "b", "\u{7f}"]);
+}
+
+#[test]
+fn lookahead() {
+    let src = "type X = async function () {} / 2\n";
+    let mut lexer = Lexer::new(src);
+
+    assert_eq!(
+        lexer.peek_kind(0),
+        Some(Kind::Ident(Some(Contextual::Type)))
+    );
+    assert_eq!(lexer.peek_kind(1), Some(Kind::Ident(None)));
+    assert_eq!(
+        lexer.peek_kind(2),
+        Some(Kind::Punctuator(Punctuator::Assign))
+    );
+    assert_eq!(lexer.peek_nth(LOOKAHEAD - 1).unwrap().text(src), "async");
+    assert!(lexer.peek_nth(LOOKAHEAD).is_none());
+    assert_eq!(lexer.peek_kind(LOOKAHEAD + 1), None);
+
+    // consume the peeked tokens without re-lexing
+    let texts: Vec<_> = lexer
+        .by_ref()
+        .take(3)
+        .map(|token| token.text(src))
+        .collect();
+    assert_eq!(texts, ["type", "X", "="]);
+
+    assert_eq!(lexer.peek_kind(1), Some(Kind::Keyword(Keyword::Function)));
+
+    // checkpoint before the peeked tokens
+    let checkpoint = lexer.checkpoint();
+    assert_eq!(checkpoint.pos(), 8);
+
+    let texts: Vec<_> = lexer
+        .by_ref()
+        .take(5)
+        .map(|token| token.text(src))
+        .collect();
+    assert_eq!(texts, ["async", "function", "(", ")", "{"]);
+
+    lexer.restore(checkpoint);
+    assert_eq!(lexer.peek().unwrap().text(src), "async");
+
+    let texts: Vec<_> = lexer
+        .by_ref()
+        .take(6)
+        .map(|token| token.text(src))
+        .collect();
+    assert_eq!(texts, ["async", "function", "(", ")", "{", "}"]);
+
+    // peeked `/` is scanned again with the new goal
+    assert_eq!(lexer.peek_kind(0), Some(Kind::Punctuator(Punctuator::Div)));
+    lexer.set_goal(Goal::RegExp);
+
+    assert!(matches!(lexer.next().unwrap().unit, Unit::Error(_)));
+    assert!(lexer.peek().is_none());
+
+    // peek inside the substitutions of nested templates, deeper than kept inline
+    let src = "`${`${`${`${`${`${a}`}`}`}`}`}` / b\n";
+    let mut lexer = Lexer::new(src);
+
+    let mut texts = Vec::new();
+
+    while lexer.peek_nth(LOOKAHEAD - 1).is_some() {
+        let checkpoint = lexer.checkpoint();
+        lexer.set_goal(Goal::Div);
+        lexer.restore(checkpoint);
+
+        texts.push(lexer.next().unwrap().text(src));
+    }
+
+    texts.extend(lexer.map(|token| token.text(src)));
+
+    assert_eq!(texts.concat(), src.replace([' ', '\n'], ""));
+    assert_eq!(texts[texts.len() - 2], "/");
+}
+
+#[test]
+fn no_trailing_new_line() {
+    for src in [
+        "", "a", "a.b", "a /b/g", "x = /[/", "// c", "/* c", "/* c *", "'abc", "\"a\\", "`a${",
+        "`a${b}", "0x", "1e", "1_", "10n", ".", "..", "?.", "#", "#a", "\\u", "\\u{61", "@", "<!",
+    ] {
+        let mut lexer = Lexer::new(src);
+        lexer.set_trivia(true);
+
+        let text: String = lexer.by_ref().map(|token| token.text(src)).collect();
+        assert_eq!(text, src);
+    }
+
+    // the last unit is still complete
+    let tokens: Vec<_> = Lexer::new("let a = 1").collect();
+    assert_eq!(tokens.len(), 4);
+    assert!(matches!(tokens[3].unit, Unit::Lit(Lit::Number("1"))));
+
+    // NUL is not the end of the source, in both the chunk and the scalar path
+    let src = format!("// a\0b{}\0\nc", "x".repeat(40));
+    let mut lexer = Lexer::new(&src);
+
+    assert!(
+        matches!(lexer.next().unwrap().unit, Unit::Comment(Comment::Line(line)) if line.ends_with('\0'))
+    );
+    assert!(matches!(lexer.next().unwrap().unit, Unit::Ident(_)));
+    assert!(lexer.next().is_none() && lexer.diagnostics().is_empty());
+
+    let tokens: Vec<_> = Lexer::new("// a\0").collect();
+    assert!(matches!(tokens[..], [Token {
+        unit: Unit::Comment(Comment::Line(" a\0")),
+        ..
+    }]));
+}
+
+#[test]
+fn long_runs() {
+    // runs cross the chunk boundaries at every offset
+    for len in 0..40 {
+        let run = "x".repeat(len);
+        let wide = "é😀".repeat(len % 7);
+
+        let indent = " ".repeat(len);
+        let ident = format!("a{run}");
+        let block = format!(" {wide}{run}\r\n{run}*{wide} ");
+        let value = format!("{run}{wide}\n{wide}{run}");
+        let line = format!(" {run}{wide}");
+
+        let src = format!(
+            "{indent}{ident} /*{block}*/ '{}' //{line}\n\t{indent}x",
+            value.replace('\n', "\\n")
+        );
+
+        let tokens: Vec<_> = Lexer::new(&src).collect();
+        assert_eq!(tokens.len(), 5, "{src:?}");
+
+        assert!(matches!(&tokens[0].unit, Unit::Ident(id) if id.raw == ident));
+        assert!(matches!(&tokens[1].unit, Unit::Comment(Comment::Block(text)) if *text == block));
+        assert!(matches!(&tokens[2].unit, Unit::Lit(Lit::String(str)) if str.cooked == value));
+        assert!(tokens[2].new_line);
+        assert!(matches!(&tokens[3].unit, Unit::Comment(Comment::Line(text)) if *text == line));
+        assert!(tokens[4].new_line);
+        assert_eq!(tokens[4].span.lo as usize, src.len() - 1);
+
+        // scalar tail starts in the middle of a char
+        let src = format!("//{line}{wide}");
+        let tokens: Vec<_> = Lexer::new(&src).collect();
+
+        assert!(matches!(&tokens[..], [token] if token.text(&src) == src));
+    }
+}
+
+#[test]
+fn batch() {
+    fn assert_send<T: Send>() {}
+
+    assert_send::<Lexer>();
+
+    let files: Vec<_> = FIXTURES.map(read_fixture).into();
+
+    let sequential: Vec<_> = files
+        .iter()
+        .map(|file| format!("{:?}", Lexed::new(file.source())))
+        .collect();
+
+    let parallel: Vec<_> = lex_files(&files)
+        .iter()
+        .map(|lexed| format!("{lexed:?}"))
+        .collect();
+
+    assert_eq!(parallel, sequential);
+
+    // more threads than sources, diagnostics stay with their source
+    let sources = ["a", "'b", "c @ \u{7f}", "", "`d"];
+    let lexed = lex_sources(&sources, NonZeroUsize::new(8).unwrap());
+
+    let counts: Vec<_> = lexed
+        .iter()
+        .map(|lexed| (lexed.tokens.len(), lexed.diagnostics.len()))
+        .collect();
+
+    assert_eq!(counts, [(1, 0), (1, 1), (3, 1), (0, 0), (1, 1)]);
+}
+
+#[test]
+fn incremental() {
+    let src =
+        "let a = 1;\nlet s = 'str';\nlet t = `x${ {b}.b }y${c}z`;\nf(a / 2, /re/g);\nx; // end\n";
+
+    let key = |token: &Token, shift: i64| {
+        let span = Span::new(
+            (token.span.lo as i64 + shift) as u32,
+            (token.span.hi as i64 + shift) as u32,
+        );
+
+        (token.unit.kind(), span, token.new_line)
+    };
+
+    let relex_and_check =
+        |src: &str, old: &[Token], restarts: &Restarts, lo: usize, from: &str, to: &str| {
+            let span = Span::new(lo as u32, (lo + from.len()) as u32);
+            let edit = TextEdit::new(span, to);
+
+            let new_src = format!("{}{to}{}", &src[..lo], &src[lo + from.len()..]);
+
+            let mut lexer = Lexer::new(&new_src);
+            let relexed = relex(&mut lexer, old, restarts, &edit);
+
+            // splice equals the full re-lex
+            let mut spliced: Vec<_> = old[..relexed.old.start]
+                .iter()
+                .map(|token| key(token, 0))
+                .collect();
+
+            spliced.extend(relexed.tokens.iter().map(|token| key(token, 0)));
+            spliced.extend(
+                old[relexed.old.end..]
+                    .iter()
+                    .map(|token| key(token, relexed.delta)),
+            );
+
+            let new: Vec<_> = Lexer::new(&new_src).collect();
+            let expected: Vec<_> = new.iter().map(|token| key(token, 0)).collect();
+
+            assert_eq!(spliced, expected, "{new_src:?}");
+
+            let mut restarts = restarts.clone();
+            restarts.splice(&new, &relexed);
+
+            (relexed.tokens.len(), new_src, restarts)
+        };
+
+    // (old text, new text, count of the new tokens) of the edit at the first match
+    for (from, to, count) in [
+        // extend an ident
+        ("a = 1", "ab = 1", 4),
+        // open a block comment which swallows the rest
+        ("1;", "1; /*", 4),
+        // open a string, unterminated until the line end
+        ("'str'", "'str", 2),
+        // close the substitution earlier
+        ("{b}.b }", "b }", 3),
+        // open a template
+        ("f(a", "`f(a", 2),
+        // `/` after an ident is division, after `(` regexp
+        ("a / 2", "(/ 2", 5),
+        ("\nx;", "\n/x;", 5),
+        // remove the line terminator before the comment
+        (";\nx", "x", 3),
+    ] {
+        let old: Vec<_> = Lexer::new(src).collect();
+        let restarts = Restarts::new(&old);
+
+        let (len, ..) = relex_and_check(src, &old, &restarts, src.find(from).unwrap(), from, to);
+        assert_eq!(len, count, "{from:?}");
+    }
+
+    // restart points far from the start, inside the substitutions, kept across the edits
+    let long = "`a${ {x: `b${ c / 2 }d`} }e` / f;\n".repeat(RESTART_INTERVAL / 2);
+    let mut src = long.clone();
+    let mut old: Vec<_> = Lexer::new(&src).collect();
+    let mut restarts = Restarts::new(&old);
+
+    for (from, to) in [
+        ("c / 2", "c / 2 / g"),
+        ("{x: ", "{x: { "),
+        ("} }e", "}e"),
+        ("2 }d", "2 } /d"),
+        ("f;\n", "f;\n/*"),
+        ("/*`", "`"),
+        (" / ", "("),
+    ] {
+        for nth in [0, 7, 15, 22, 29] {
+            let Some((lo, _)) = src.match_indices(from).nth(nth) else {
+                continue
+            };
+
+            let (_, new_src, new_restarts) = relex_and_check(&src, &old, &restarts, lo, from, to);
+
+            src = new_src;
+            old = Lexer::new(&src).collect();
+            restarts = new_restarts;
+        }
+    }
+
+    assert_ne!(src, long);
+}
+
+#[test]
+fn atom() {
+    let interner = Interner::new();
+
+    // seeded with fixed ids
+    for &keyword in Keyword::ALL {
+        assert_eq!(interner.get(keyword.raw()), Some(Atom::from(keyword)));
+    }
+
+    for &contextual in Contextual::ALL {
+        assert_eq!(interner.intern(contextual.raw()), Atom::from(contextual));
+    }
+
+    assert!(interner.get("eval").is_some_and(Atom::is_static));
+    assert_eq!(interner.get("foo"), None);
+
+    let seeded = interner.len();
+
+    // identifier and string literal of the same text
+    let src = "foo \\u0066oo 'foo' type \"b\\x61r\" bar\n";
+    let atoms: Vec<_> = Lexer::new(src)
+        .map(|token| {
+            match &token.unit {
+                Unit::Ident(ident) => ident.atom(&interner),
+                Unit::Lit(Lit::String(str)) => str.atom(&interner),
+                unit => panic!("unexpected {unit:?}"),
+            }
+        })
+        .collect();
+
+    assert_eq!(atoms[0], atoms[1]);
+    assert_eq!(atoms[0], atoms[2]);
+    assert_eq!(atoms[3], Atom::from(Contextual::Type));
+    assert_eq!(atoms[4], atoms[5]);
+    assert_ne!(atoms[0], atoms[4]);
+
+    assert_eq!(interner.resolve(atoms[0]), "foo");
+    assert_eq!(interner.resolve(atoms[3]), "type");
+    assert_eq!(interner.len(), seeded + 2);
+
+    // shared across threads
+    let interner = Arc::new(interner);
+
+    let atoms: Vec<Vec<_>> = (0..4)
+        .map(|_| {
+            let interner = interner.clone();
 
-    let content: String = lexer.map(|unit| format!("{unit:?}\n")).collect();
+            thread::spawn(move || {
+                (0..100)
+                    .map(|n| interner.intern(&format!("name{n}")))
+                    .collect()
+            })
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
 
-    assert_snapshot!(content);
+    assert!(atoms.iter().all(|other| *other == atoms[0]));
+    assert_eq!(interner.len(), seeded + 102);
+    assert_eq!(interner.resolve(atoms[0][42]), "name42");
 }
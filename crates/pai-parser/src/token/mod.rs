@@ -1,5 +1,5 @@
 use kind::Kind;
-use span::Span;
+pub use span::{Pos, Span, Tier};
 
 mod kind;
 mod span;
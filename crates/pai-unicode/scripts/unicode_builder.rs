@@ -57,7 +57,7 @@ impl FromStr for CodePointRange {
 
 impl From<CodePointRange> for String {
     fn from(CodePointRange { lo, hi }: CodePointRange) -> Self {
-        fmt::format(format_args_nl!("(0x{lo:05X},0x{hi:05X})"))
+        format!("(0x{lo:05X},0x{hi:05X})\n")
     }
 }
 